@@ -0,0 +1,186 @@
+use crate::{
+    answers::{Answers, Verdict},
+    days::{self, Solved},
+    input::InputDir,
+    output::{millis, Format, Record},
+};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A day that could not be solved, with why: unreadable or invalid input, no
+/// solution yet or a panic.
+#[derive(Serialize)]
+struct ErrorRecord<'a> {
+    day: u8,
+    error: &'a str,
+}
+
+struct Row {
+    day: u8,
+    part: Option<u8>,
+    answer: String,
+    status: String,
+    time: String,
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    "unknown panic".to_owned()
+}
+
+/// Solves a single day, turning unreadable input, parse errors and panics
+/// into an error message instead of aborting the whole run.
+fn solve_day(day: u8, input_dir: &InputDir) -> Result<Solved, String> {
+    let source = input_dir.source(day);
+    let input = source
+        .read()
+        .map_err(|err| format!("failed to read {}: {}", source, err))?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, &input, None))) {
+        Ok(Some(Ok(solved))) => Ok(solved),
+        Ok(Some(Err(err))) => Err(format!("invalid input in {}: {}", source, err)),
+        Ok(None) => Err("not solved yet".to_owned()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .flat_map(|row| row.answer.lines())
+        .map(str::len)
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:<7}  {:>10}",
+        "Day",
+        "Part",
+        "Answer",
+        "Status",
+        "Time",
+        width = answer_width
+    );
+
+    for row in rows {
+        let part = row.part.map(|part| part.to_string()).unwrap_or_default();
+        let mut lines = row.answer.lines();
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:<7}  {:>10}",
+            row.day,
+            part,
+            lines.next().unwrap_or_default(),
+            row.status,
+            row.time,
+            width = answer_width
+        );
+
+        for line in lines {
+            println!("{:>3}  {:>4}  {}", "", "", line);
+        }
+    }
+}
+
+/// Solves every registered day, concurrently unless `sequential` is set, and
+/// reports them in order. Returns whether all of them succeeded without a
+/// mismatching answer.
+pub fn run_all(
+    format: Format,
+    input_dir: &InputDir,
+    answers: Option<&Answers>,
+    sequential: bool,
+) -> bool {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let results: Vec<(u8, Result<Solved, String>)> = match sequential {
+        true => days::DAYS
+            .iter()
+            .map(|&day| (day, solve_day(day, input_dir)))
+            .collect(),
+        false => days::DAYS
+            .par_iter()
+            .map(|&day| (day, solve_day(day, input_dir)))
+            .collect(),
+    };
+
+    panic::set_hook(default_hook);
+
+    let mut success = true;
+    let mut rows = Vec::new();
+    let mut notes = Vec::new();
+    for (day, result) in results {
+        let solved = match result {
+            Ok(solved) => solved,
+            Err(error) => {
+                success = false;
+                match format {
+                    Format::Json => {
+                        let record = ErrorRecord { day, error: &error };
+                        println!("{}", serde_json::to_string(&record).unwrap());
+                    }
+                    Format::Text => {
+                        rows.push(Row {
+                            day,
+                            part: None,
+                            answer: String::new(),
+                            status: "error".to_owned(),
+                            time: String::new(),
+                        });
+                        notes.push(format!("Day {}: {}", day, error));
+                    }
+                }
+                continue;
+            }
+        };
+
+        for result in &solved.parts {
+            let part = result.part;
+            let verdict = answers
+                .map(|answers| answers.check(day, part, &result.answer))
+                .unwrap_or(Verdict::Unknown);
+            if let Verdict::Fail { expected } = &verdict {
+                success = false;
+                notes.push(format!("Day {} part {}: expected {}", day, part, expected));
+            }
+
+            match format {
+                Format::Json => {
+                    let record = Record {
+                        day,
+                        part,
+                        answer: &result.answer,
+                        elapsed_ms: millis(result.elapsed),
+                        status: Some(verdict.status()),
+                    };
+                    println!("{}", serde_json::to_string(&record).unwrap());
+                }
+                Format::Text => rows.push(Row {
+                    day,
+                    part: Some(part),
+                    answer: result.answer.clone(),
+                    status: verdict.status().to_owned(),
+                    time: format!("{:.3} ms", millis(result.elapsed)),
+                }),
+            }
+        }
+    }
+
+    if let Format::Text = format {
+        print_table(&rows);
+        for note in notes {
+            println!("{}", note);
+        }
+    }
+
+    success
+}
@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use std::{collections::HashMap, error::Error, fmt, fs, path::Path};
+
+pub const DEFAULT_ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Known answers keyed by day, e.g.
+///
+/// ```toml
+/// [day1]
+/// part1 = 24000
+/// part2 = "45000"
+/// ```
+#[derive(Deserialize)]
+pub struct Answers(HashMap<String, DayAnswers>);
+
+#[derive(Deserialize)]
+struct DayAnswers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    fn expected(&self, day: u8, part: u8) -> Option<String> {
+        let day = self.0.get(&format!("day{}", day))?;
+        let value = match part {
+            1 => day.part1.as_ref(),
+            _ => day.part2.as_ref(),
+        }?;
+
+        match value {
+            toml::Value::String(value) => Some(value.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.expected(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if same_answer(&expected, answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+        }
+    }
+}
+
+impl Verdict {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Compares answers line by line, ignoring trailing whitespace so multi-line
+/// drawings survive editors and TOML string quoting.
+fn same_answer(expected: &str, answer: &str) -> bool {
+    let expected = expected.trim_matches('\n').lines().map(str::trim_end);
+    let answer = answer.trim_matches('\n').lines().map(str::trim_end);
+    expected.eq(answer)
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } if expected.contains('\n') => {
+                write!(f, "FAIL, expected:\n{}", expected.trim_matches('\n'))
+            }
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
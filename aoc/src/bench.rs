@@ -0,0 +1,114 @@
+use crate::{
+    days::{self, Solved},
+    output::{millis, Format},
+};
+use common::Error;
+use serde::Serialize;
+use std::time::Duration;
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            runs: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Statistics of every stage in [`STAGES`] order, `None` for skipped stages.
+pub type StageStats = [Option<Stats>; 3];
+
+/// Fastest, median and slowest time of one stage of a day over `runs` runs, in
+/// milliseconds.
+#[derive(Serialize)]
+struct StatsRecord {
+    day: u8,
+    stage: &'static str,
+    runs: usize,
+    min_ms: f64,
+    median_ms: f64,
+    max_ms: f64,
+}
+
+/// Solves `day` `runs` times, only `part` when given, returning the first
+/// solution along with the timing statistics for the parse, part 1 and part 2
+/// stages. Stages that were skipped have no statistics.
+pub fn measure(
+    day: u8,
+    input: &str,
+    part: Option<u8>,
+    runs: usize,
+) -> Option<Result<(Solved, StageStats), Error>> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    let mut first = None;
+
+    for _ in 0..runs.max(1) {
+        let solved = match days::solve(day, input, part)? {
+            Ok(solved) => solved,
+            Err(err) => return Some(Err(err)),
+        };
+
+        samples[0].push(solved.parse_time);
+        for result in &solved.parts {
+            samples[usize::from(result.part)].push(result.elapsed);
+        }
+        first.get_or_insert(solved);
+    }
+
+    let stats = samples.map(|samples| (!samples.is_empty()).then(|| Stats::from_samples(samples)));
+    Some(Ok((first.unwrap(), stats)))
+}
+
+pub fn print_stats_header(format: Format) {
+    if let Format::Text = format {
+        println!(
+            "{:>3}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}",
+            "Day", "Stage", "Runs", "Min", "Median", "Max"
+        );
+    }
+}
+
+pub fn print_stats(format: Format, day: u8, stats: &StageStats) {
+    for (stage, stats) in STAGES.iter().zip(stats.iter()) {
+        let Some(stats) = stats else {
+            continue;
+        };
+
+        match format {
+            Format::Text => println!(
+                "{:>3}  {:<5}  {:>5}  {:>9.3} ms  {:>9.3} ms  {:>9.3} ms",
+                day,
+                stage,
+                stats.runs,
+                millis(stats.min),
+                millis(stats.median),
+                millis(stats.max)
+            ),
+            Format::Json => {
+                let record = StatsRecord {
+                    day,
+                    stage,
+                    runs: stats.runs,
+                    min_ms: millis(stats.min),
+                    median_ms: millis(stats.median),
+                    max_ms: millis(stats.max),
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+    }
+}
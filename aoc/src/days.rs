@@ -0,0 +1,75 @@
+use common::{Error, Solution};
+use std::time::{Duration, Instant};
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn run<S: Solution>(input: &str, part: Option<u8>) -> Result<Solved, Error> {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed?;
+
+    let mut parts = Vec::new();
+    if part != Some(2) {
+        let (answer, elapsed) = timed(|| S::part1(&parsed).to_string());
+        parts.push(PartResult {
+            part: 1,
+            answer,
+            elapsed,
+        });
+    }
+    if part != Some(1) {
+        let (answer, elapsed) = timed(|| S::part2(&parsed).to_string());
+        parts.push(PartResult {
+            part: 2,
+            answer,
+            elapsed,
+        });
+    }
+
+    Ok(Solved { parse_time, parts })
+}
+
+macro_rules! days {
+    ($($day:literal => $solution:ty,)*) => {
+        /// Every day with a registered solution.
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        /// Solves `day`, only `part` when given, `None` when the day has no
+        /// solution.
+        pub fn solve(day: u8, input: &str, part: Option<u8>) -> Option<Result<Solved, Error>> {
+            let solved = match day {
+                $($day => run::<$solution>(input, part),)*
+                _ => return None,
+            };
+
+            Some(solved)
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+}
@@ -0,0 +1,297 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{
+    error::Error,
+    io::{self, Write},
+};
+
+pub const DEFAULT_SIZE: usize = 1000;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Writes a valid puzzle input for `day` to `out`, scaled by `size` and fully
+/// determined by `seed`.
+pub fn generate(
+    day: u8,
+    size: usize,
+    seed: u64,
+    out: &mut impl Write,
+) -> Result<(), Box<dyn Error>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    match day {
+        1 => calories(&mut rng, size, out)?,
+        2 => strategy_guide(&mut rng, size, out)?,
+        3 => rucksacks(&mut rng, size, out)?,
+        4 => assignments(&mut rng, size, out)?,
+        5 => crane_procedure(&mut rng, size, out)?,
+        6 => datastream(&mut rng, size, out)?,
+        7 => terminal_output(&mut rng, size, out)?,
+        8 => forest(&mut rng, size, out)?,
+        9 => motions(&mut rng, size, out)?,
+        10 => program(&mut rng, size, out)?,
+        _ => return Err(format!("no generator for day {}", day).into()),
+    }
+
+    Ok(())
+}
+
+fn calories(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    let mut remaining = size.max(1);
+    while remaining > 0 {
+        let items = rng.gen_range(1..=15).min(remaining);
+        for _ in 0..items {
+            writeln!(out, "{}", rng.gen_range(1000..=60000))?;
+        }
+
+        remaining -= items;
+        if remaining > 0 {
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn strategy_guide(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    for _ in 0..size.max(1) {
+        let opponent = *[b'A', b'B', b'C'].choose(rng).unwrap() as char;
+        let response = *[b'X', b'Y', b'Z'].choose(rng).unwrap() as char;
+        writeln!(out, "{} {}", opponent, response)?;
+    }
+
+    Ok(())
+}
+
+/// Groups of three backpacks sharing exactly one badge, where each backpack
+/// has exactly one item type in both compartments.
+fn rucksacks(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    for _ in 0..size.max(1).div_ceil(3) {
+        let mut letters = LETTERS.to_vec();
+        letters.shuffle(rng);
+        let (badge, pools) = letters.split_first().unwrap();
+
+        for pool in pools.chunks(pools.len() / 3).take(3) {
+            let (misplaced, only) = pool.split_first().unwrap();
+            let (only_a, only_b) = only.split_at(only.len() / 2);
+            let length = rng.gen_range(3..=16);
+
+            let mut compartment_a = vec![*misplaced, *badge];
+            let mut compartment_b = vec![*misplaced];
+            compartment_a.extend((2..length).map(|_| *only_a.choose(rng).unwrap()));
+            compartment_b.extend((1..length).map(|_| *only_b.choose(rng).unwrap()));
+            compartment_a.shuffle(rng);
+            compartment_b.shuffle(rng);
+
+            out.write_all(&compartment_a)?;
+            out.write_all(&compartment_b)?;
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn assignments(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    for _ in 0..size.max(1) {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            (start, rng.gen_range(start..=99))
+        };
+        let (a, b) = (range(), range());
+        writeln!(out, "{}-{},{}-{}", a.0, a.1, b.0, b.1)?;
+    }
+
+    Ok(())
+}
+
+/// Nine stacks and moves that never take the last crate off a stack, so
+/// every stack has a top crate at the end.
+fn crane_procedure(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    const STACKS: usize = 9;
+
+    let mut heights: Vec<usize> = (0..STACKS).map(|_| rng.gen_range(1..=8)).collect();
+    heights[0] = heights[0].max(2);
+    let tallest = *heights.iter().max().unwrap();
+    for level in (0..tallest).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|&height| match level < height {
+                true => format!("[{}]", (b'A' + rng.gen_range(0..26)) as char),
+                false => "   ".to_owned(),
+            })
+            .collect();
+        writeln!(out, "{}", row.join(" ").trim_end())?;
+    }
+
+    let numbers: Vec<String> = (1..=STACKS).map(|stack| format!(" {} ", stack)).collect();
+    writeln!(out, "{}", numbers.join(" ").trim_end())?;
+    writeln!(out)?;
+
+    for _ in 0..size.max(1) {
+        let sources: Vec<usize> = (0..STACKS).filter(|&stack| heights[stack] > 1).collect();
+        let source = *sources.choose(rng).unwrap();
+        let destination = (source + rng.gen_range(1..STACKS)) % STACKS;
+        let count = rng.gen_range(1..heights[source]);
+
+        heights[source] -= count;
+        heights[destination] += count;
+        writeln!(
+            out,
+            "move {} from {} to {}",
+            count,
+            source + 1,
+            destination + 1
+        )?;
+    }
+
+    Ok(())
+}
+
+/// A stream drawn from three letters, which can never hold a marker, with both
+/// markers at the very end so the whole stream has to be scanned.
+fn datastream(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    let mut stream: Vec<u8> = (0..size).map(|_| b'a' + rng.gen_range(0..3)).collect();
+    let mut marker = LETTERS[3..26].to_vec();
+    marker.shuffle(rng);
+    stream.extend(&marker[..14]);
+
+    out.write_all(&stream)?;
+    writeln!(out)
+}
+
+fn name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(LETTERS[index % 26]);
+        index /= 26;
+        if index == 0 {
+            break;
+        }
+    }
+
+    String::from_utf8(name).unwrap()
+}
+
+/// A random directory tree, biased towards deep chains, listed in depth-first
+/// order. File sizes add up to 55 000 000 so part 2 always has something to
+/// delete.
+fn terminal_output(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    const TOTAL_SIZE: u64 = 55_000_000;
+
+    let directories = size.max(1);
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); directories];
+    for directory in 1..directories {
+        let parent = match rng.gen_bool(0.7) {
+            true => directory - 1,
+            false => rng.gen_range(0..directory),
+        };
+        children[parent].push(directory);
+    }
+
+    let mut files: Vec<usize> = (0..directories).map(|_| rng.gen_range(0..=5)).collect();
+    files[0] = files[0].max(1);
+    let weights: Vec<u64> = (0..files.iter().sum::<usize>())
+        .map(|_| rng.gen_range(1..=1000))
+        .collect();
+    let total_weight: u64 = weights.iter().sum();
+    let mut sizes = weights
+        .iter()
+        .map(|weight| (weight * TOTAL_SIZE / total_weight).max(1));
+
+    writeln!(out, "$ cd /")?;
+    let mut to_visit = vec![Some(0)];
+    while let Some(next) = to_visit.pop() {
+        let Some(directory) = next else {
+            writeln!(out, "$ cd ..")?;
+            continue;
+        };
+
+        if directory > 0 {
+            writeln!(out, "$ cd {}", name(directory))?;
+        }
+
+        writeln!(out, "$ ls")?;
+        for child in &children[directory] {
+            writeln!(out, "dir {}", name(*child))?;
+        }
+        for file in 0..files[directory] {
+            writeln!(out, "{} {}.txt", sizes.next().unwrap(), name(file))?;
+        }
+
+        for child in children[directory].iter().rev() {
+            to_visit.push(None);
+            to_visit.push(Some(*child));
+        }
+    }
+
+    Ok(())
+}
+
+fn forest(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    let side = size.max(1);
+    let mut row = vec![0; side];
+    for _ in 0..side {
+        row.iter_mut()
+            .for_each(|tree| *tree = b'0' + rng.gen_range(0..10));
+        out.write_all(&row)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn motions(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    for _ in 0..size.max(1) {
+        let direction = *[b'U', b'D', b'L', b'R'].choose(rng).unwrap() as char;
+        writeln!(out, "{} {}", direction, rng.gen_range(1..=20))?;
+    }
+
+    Ok(())
+}
+
+/// A program that keeps the register near the screen so the drawing stays
+/// interesting however long it runs.
+fn program(rng: &mut StdRng, size: usize, out: &mut impl Write) -> io::Result<()> {
+    let mut register: i32 = 1;
+    for _ in 0..size.max(1) {
+        if rng.gen_bool(0.3) {
+            writeln!(out, "noop")?;
+            continue;
+        }
+
+        let target = rng.gen_range(-1..=40);
+        let value = (target - register).clamp(-20, 20);
+        register += value;
+        writeln!(out, "addx {}", value)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn generated(day: u8, size: usize, seed: u64) -> String {
+        let mut out = Vec::new();
+        generate(day, size, seed, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn every_day_solves_generated_input() {
+        for &day in days::DAYS {
+            for size in [0, 1, 2, 100] {
+                let input = generated(day, size, 7);
+                let solved = days::solve(day, &input, None).unwrap();
+                assert!(solved.is_ok(), "day {} size {}", day, size);
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        assert_eq!(generated(9, 50, 1), generated(9, 50, 1));
+        assert_ne!(generated(9, 50, 1), generated(9, 50, 2));
+    }
+}
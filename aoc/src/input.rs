@@ -0,0 +1,106 @@
+use clap::Args;
+use common::normalize_input;
+use std::{
+    fmt, fs,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+};
+
+const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Args)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file
+    #[arg(long, short, group = "source")]
+    input: Option<PathBuf>,
+
+    /// Read the puzzle input from stdin
+    #[arg(long, group = "source")]
+    stdin: bool,
+
+    /// Use this string as the puzzle input
+    #[arg(long, group = "source")]
+    inline: Option<String>,
+
+    #[command(flatten)]
+    dir: InputDir,
+}
+
+#[derive(Args)]
+pub struct InputDir {
+    /// Directory holding the default dayN/input.txt files
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+}
+
+impl InputDir {
+    pub fn source(&self, day: u8) -> InputSource {
+        InputSource::File(
+            self.input_dir
+                .join(format!("day{}", day))
+                .join("input.txt"),
+        )
+    }
+}
+
+impl InputArgs {
+    pub fn source(&self, day: u8) -> InputSource {
+        if let Some(path) = &self.input {
+            return InputSource::File(path.clone());
+        }
+
+        if self.stdin {
+            return InputSource::Stdin;
+        }
+
+        if let Some(input) = &self.inline {
+            return InputSource::Inline(input.clone());
+        }
+
+        self.dir.source(day)
+    }
+}
+
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Reads the puzzle input with normalized line endings and no trailing
+    /// whitespace, whatever editor or platform produced it.
+    pub fn read(&self) -> io::Result<String> {
+        let input = match self {
+            InputSource::File(path) => fs::read_to_string(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            InputSource::Inline(input) => input.clone(),
+        };
+
+        Ok(normalize_input(&input))
+    }
+
+    /// Opens the puzzle input for reading one line at a time, without
+    /// normalizing it.
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            InputSource::File(path) => Box::new(BufReader::new(fs::File::open(path)?)),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Inline(input) => Box::new(Cursor::new(input.clone().into_bytes())),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Inline(_) => write!(f, "inline input"),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+mod all;
+mod answers;
+mod bench;
+mod days;
+mod generate;
+mod input;
+mod output;
+mod repl;
+mod report;
+mod scaffold;
+
+use answers::{Answers, Verdict, DEFAULT_ANSWERS_PATH};
+use clap::{Parser, Subcommand};
+use common::Solution;
+use input::{InputArgs, InputDir, InputSource};
+use output::{millis, print_record, print_top_elves, ElfRecord, Format, Record, SummaryRecord};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Output format for answers
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day
+    Run {
+        /// Day to solve
+        day: u8,
+
+        /// Only solve this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Verify the answers against the answers file
+        #[arg(long)]
+        check: bool,
+
+        /// TOML file with the known answers per day and part
+        #[arg(long, env = "AOC_ANSWERS", default_value = DEFAULT_ANSWERS_PATH)]
+        answers: PathBuf,
+
+        /// Print how long parsing and each part took
+        #[arg(long)]
+        time: bool,
+
+        /// Solve this many times and report min/median/max timings
+        #[arg(long, default_value_t = 1)]
+        repeat: usize,
+    },
+
+    /// Time every day over repeated runs
+    Bench {
+        /// Days to benchmark, all days if omitted
+        days: Vec<u8>,
+
+        /// Number of runs per day
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+
+        #[command(flatten)]
+        input: InputDir,
+    },
+
+    /// Solve every day and print a summary table
+    All {
+        #[command(flatten)]
+        input: InputDir,
+
+        /// TOML file with the known answers per day and part, if present
+        #[arg(long, env = "AOC_ANSWERS", default_value = DEFAULT_ANSWERS_PATH)]
+        answers: PathBuf,
+
+        /// Solve the days one after another instead of concurrently, for
+        /// timings that do not compete for cores
+        #[arg(long)]
+        sequential: bool,
+    },
+
+    /// Generate a large random puzzle input for stress testing a day
+    Generate {
+        day: u8,
+
+        /// Scale of the input: lines for days 1 to 4, steps for day 5, stream
+        /// length for day 6, directories for day 7, forest side for day 8,
+        /// motions for day 9 and instructions for day 10
+        #[arg(long, default_value_t = generate::DEFAULT_SIZE)]
+        size: usize,
+
+        /// Seed for the random generator, the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Write the input to this file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Show the elves carrying the most calories on day 1, reading the input
+    /// one line at a time
+    Top {
+        /// Number of elves to show
+        #[arg(default_value_t = 3)]
+        count: usize,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Report statistics over the day 1 inventory, with a histogram of the
+    /// calories carried per elf
+    Report {
+        /// Percentile to report alongside the median
+        #[arg(long, default_value_t = 90, value_parser = clap::value_parser!(u8).range(0..=100))]
+        percentile: u8,
+
+        /// Number of histogram buckets
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+        buckets: u16,
+
+        /// Also report the items of every elf
+        #[arg(long)]
+        per_elf: bool,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Explore the parsed puzzle input of a day interactively, reading
+    /// commands from stdin
+    Repl {
+        day: u8,
+
+        #[command(flatten)]
+        input: InputArgs,
+    },
+
+    /// Generate a new day crate from the template and register it
+    New {
+        day: u8,
+
+        /// Workspace root to create the day in
+        #[arg(long, default_value = scaffold::DEFAULT_ROOT)]
+        root: PathBuf,
+    },
+}
+
+fn read_input(source: &InputSource) -> Option<String> {
+    match source.read() {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("Failed to read {}: {}", source, err);
+            None
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            check,
+            answers,
+            time,
+            repeat,
+        } => {
+            let answers = match check.then(|| Answers::load(&answers)).transpose() {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("Failed to load {}: {}", answers.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let source = input.source(day);
+            let Some(input) = read_input(&source) else {
+                return ExitCode::FAILURE;
+            };
+
+            let (solved, stats) = match bench::measure(day, &input, part, repeat) {
+                Some(Ok(measured)) => measured,
+                Some(Err(err)) => {
+                    eprintln!("Invalid input in {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("Day {} is not solved yet", day);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let mut failed = false;
+            for result in &solved.parts {
+                let verdict = answers
+                    .as_ref()
+                    .map(|answers| answers.check(day, result.part, &result.answer));
+                failed |= matches!(verdict, Some(Verdict::Fail { .. }));
+
+                let record = Record {
+                    day,
+                    part: result.part,
+                    answer: &result.answer,
+                    elapsed_ms: millis(result.elapsed),
+                    status: verdict.as_ref().map(Verdict::status),
+                };
+                print_record(cli.format, &record, verdict.as_ref());
+            }
+
+            if time || repeat > 1 {
+                bench::print_stats_header(cli.format);
+                bench::print_stats(cli.format, day, &stats);
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Bench { days, runs, input } => {
+            let days = match days.is_empty() {
+                true => days::DAYS.to_vec(),
+                false => days,
+            };
+
+            let mut failed = false;
+            bench::print_stats_header(cli.format);
+            for day in days {
+                let source = input.source(day);
+                let Some(input) = read_input(&source) else {
+                    failed = true;
+                    continue;
+                };
+
+                match bench::measure(day, &input, None, runs) {
+                    Some(Ok((_, stats))) => bench::print_stats(cli.format, day, &stats),
+                    Some(Err(err)) => {
+                        eprintln!("Invalid input in {}: {}", source, err);
+                        failed = true;
+                    }
+                    None => {
+                        eprintln!("Day {} is not solved yet", day);
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::All {
+            input,
+            answers,
+            sequential,
+        } => {
+            let answers = match answers.exists() {
+                true => match Answers::load(&answers) {
+                    Ok(answers) => Some(answers),
+                    Err(err) => {
+                        eprintln!("Failed to load {}: {}", answers.display(), err);
+                        return ExitCode::FAILURE;
+                    }
+                },
+                false => None,
+            };
+
+            if !all::run_all(cli.format, &input, answers.as_ref(), sequential) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let mut out: Box<dyn Write> = match &output {
+                Some(path) => match File::create(path) {
+                    Ok(file) => Box::new(BufWriter::new(file)),
+                    Err(err) => {
+                        eprintln!("Failed to create {}: {}", path.display(), err);
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => Box::new(BufWriter::new(io::stdout().lock())),
+            };
+
+            let generated = generate::generate(day, size, seed, &mut out);
+            if let Err(err) = generated.and_then(|_| Ok(out.flush()?)) {
+                eprintln!("Failed to generate day {}: {}", day, err);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Top { count, input } => {
+            let source = input.source(1);
+            let tally = match source
+                .reader()
+                .and_then(|reader| day1::stream(reader, count))
+            {
+                Ok(tally) => tally,
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                    eprintln!("Invalid input in {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+                Err(err) => {
+                    eprintln!("Failed to read {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let records: Vec<ElfRecord> = tally
+                .top()
+                .into_iter()
+                .enumerate()
+                .map(|(rank, (index, calories))| ElfRecord {
+                    rank: rank + 1,
+                    elf: index + 1,
+                    calories,
+                })
+                .collect();
+            let summary = SummaryRecord {
+                elves: tally.elves(),
+                items: tally.items(),
+                total: tally.total(),
+                mean: tally.mean(),
+                smallest: tally.smallest(),
+                largest: tally.largest(),
+            };
+            print_top_elves(cli.format, &records, &summary);
+        }
+        Command::Report {
+            percentile,
+            buckets,
+            per_elf,
+            input,
+        } => {
+            let source = input.source(1);
+            let Some(input) = read_input(&source) else {
+                return ExitCode::FAILURE;
+            };
+
+            match day1::Day1::parse(&input) {
+                Ok(elves) => {
+                    report::print_report(cli.format, &elves, percentile, buckets.into(), per_elf)
+                }
+                Err(err) => {
+                    eprintln!("Invalid input in {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Repl { day, input } => {
+            let source = input.source(day);
+            if let InputSource::Stdin = source {
+                eprintln!(
+                    "The REPL reads commands from stdin, pass the input with --input or --inline"
+                );
+                return ExitCode::FAILURE;
+            }
+
+            let Some(input) = read_input(&source) else {
+                return ExitCode::FAILURE;
+            };
+
+            let mut explorer = match repl::explorer(day, &input) {
+                Some(Ok(explorer)) => explorer,
+                Some(Err(err)) => {
+                    eprintln!("Invalid input in {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("Day {} is not solved yet", day);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let stdin = io::stdin().lock();
+            if let Err(err) = repl::run(day, explorer.as_mut(), stdin, &mut io::stdout()) {
+                eprintln!("Failed to run the REPL: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { day, root } => match scaffold::new_day(&root, day) {
+            Ok(dir) => println!("Created {}", dir.display()),
+            Err(err) => {
+                eprintln!("Failed to create day {}: {}", day, err);
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,97 @@
+use crate::answers::Verdict;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Aligned tables for reading in a terminal
+    Text,
+    /// One JSON object per line, in the order the text output would list
+    /// them, for piping into other tools
+    Json,
+}
+
+/// The answer to one part of a day and how long solving it took, with the
+/// verdict against the known answers when they were checked.
+#[derive(Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    pub elapsed_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'static str>,
+}
+
+/// One of the elves carrying the most calories: its rank among them, its
+/// 1-based position in the inventory and what it carries.
+#[derive(Serialize)]
+pub struct ElfRecord {
+    pub rank: usize,
+    pub elf: usize,
+    pub calories: u64,
+}
+
+/// Counts and totals over the whole inventory, following the top elves. The
+/// mean and extremes are null when there is no elf.
+#[derive(Serialize)]
+pub struct SummaryRecord {
+    pub elves: usize,
+    pub items: usize,
+    pub total: u64,
+    pub mean: Option<f64>,
+    pub smallest: Option<u64>,
+    pub largest: Option<u64>,
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn print_record(format: Format, record: &Record, verdict: Option<&Verdict>) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string(record).unwrap()),
+        Format::Text => {
+            if record.answer.contains('\n') {
+                println!("Part {}:\n{}", record.part, record.answer);
+            } else {
+                println!("Part {}: {}", record.part, record.answer);
+            }
+
+            if let Some(verdict) = verdict {
+                println!("  {}", verdict);
+            }
+        }
+    }
+}
+
+pub fn print_top_elves(format: Format, records: &[ElfRecord], summary: &SummaryRecord) {
+    match format {
+        Format::Json => {
+            for record in records {
+                println!("{}", serde_json::to_string(record).unwrap());
+            }
+            println!("{}", serde_json::to_string(summary).unwrap());
+        }
+        Format::Text => {
+            for record in records {
+                println!("{}. Elf {}: {}", record.rank, record.elf, record.calories);
+            }
+
+            let total: u64 = records.iter().map(|record| record.calories).sum();
+            println!("Total: {}", total);
+
+            let describe = |value: Option<u64>| value.map_or("-".to_owned(), |v| v.to_string());
+            println!(
+                "\n{} elves, {} items, {} calories, {:.1} per elf, {} to {}",
+                summary.elves,
+                summary.items,
+                summary.total,
+                summary.mean.unwrap_or(0.0),
+                describe(summary.smallest),
+                describe(summary.largest)
+            );
+        }
+    }
+}
@@ -0,0 +1,556 @@
+use common::{Error, Point, Solution};
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Write},
+};
+
+const HELP: &str = "\
+part1, part2      answer a part for the loaded input
+help              show this help
+quit, exit        leave";
+
+/// The parsed model of a day, queried one command at a time.
+pub trait Explorer {
+    /// Commands specific to the day, one per line, shown by `help`.
+    fn help(&self) -> &'static str {
+        ""
+    }
+
+    /// Answer to `part` 1 or 2 for the loaded input.
+    fn answer(&self, part: u8) -> String;
+
+    /// Runs a day-specific command, `None` when the day has no such command.
+    fn command(&mut self, _name: &str, _args: &[&str]) -> Option<Result<String, String>> {
+        None
+    }
+}
+
+fn answer<S: Solution>(input: &S::Input, part: u8) -> String {
+    match part {
+        1 => S::part1(input).to_string(),
+        _ => S::part2(input).to_string(),
+    }
+}
+
+/// A day without commands of its own, which can only be answered.
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Explorer for Parsed<S> {
+    fn answer(&self, part: u8) -> String {
+        answer::<S>(&self.0, part)
+    }
+}
+
+fn arg<T: std::str::FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let arg = args.get(index).ok_or_else(|| format!("missing {}", name))?;
+    arg.parse()
+        .map_err(|_| format!("expected {}, found `{}`", name, arg))
+}
+
+fn optional_arg<T: std::str::FromStr>(
+    args: &[&str],
+    index: usize,
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match args.get(index) {
+        Some(_) => arg(args, index, name),
+        None => Ok(default),
+    }
+}
+
+/// Day 5: the stacks part way through the procedure.
+struct Crane {
+    procedure: day5::Procedure,
+    cargo: day5::Cargo,
+    step: usize,
+    reverse_picked: bool,
+}
+
+impl Crane {
+    fn new(procedure: day5::Procedure) -> Self {
+        Self {
+            cargo: procedure.cargo().clone(),
+            procedure,
+            step: 0,
+            reverse_picked: true,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cargo = self.procedure.cargo().clone();
+        self.step = 0;
+    }
+
+    fn stack(&self, number: usize) -> Result<String, String> {
+        let stack = number
+            .checked_sub(1)
+            .and_then(|index| self.cargo.stacks().get(index))
+            .ok_or_else(|| format!("no stack {}", number))?;
+        Ok(format!("{}: {}", number, stack.iter().collect::<String>()))
+    }
+
+    fn step(&mut self, count: usize) -> String {
+        let mut applied = Vec::new();
+        for _ in 0..count {
+            let Some(instruction) = self.procedure.instructions().get(self.step) else {
+                applied.push("procedure finished".to_owned());
+                break;
+            };
+
+            self.cargo = self
+                .cargo
+                .clone()
+                .apply_instruction(instruction, self.reverse_picked);
+            self.step += 1;
+            applied.push(format!(
+                "{}: move {} from {} to {}",
+                self.step, instruction.count, instruction.source, instruction.destination
+            ));
+        }
+
+        applied.join("\n")
+    }
+}
+
+impl Explorer for Crane {
+    fn help(&self) -> &'static str {
+        "\
+stacks            show every stack, bottom to top
+stack <n>         show stack n
+step [count]      run the next steps, 1 by default
+crane 9000|9001   move crates one at a time or all at once, and reset
+reset             go back to the starting stacks"
+    }
+
+    fn answer(&self, part: u8) -> String {
+        answer::<day5::Day5>(&self.procedure, part)
+    }
+
+    fn command(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>> {
+        let result = match name {
+            "stacks" => Ok((1..=self.cargo.stacks().len())
+                .map(|number| self.stack(number).unwrap())
+                .collect::<Vec<String>>()
+                .join("\n")),
+            "stack" => arg(args, 0, "a stack number").and_then(|number| self.stack(number)),
+            "step" => optional_arg(args, 0, "a step count", 1).map(|count| self.step(count)),
+            "crane" => match args.first() {
+                Some(&"9000") | Some(&"9001") => {
+                    self.reverse_picked = args[0] == "9000";
+                    self.reset();
+                    Ok(format!("using the CrateMover {}", args[0]))
+                }
+                _ => Err("expected `9000` or `9001`".to_owned()),
+            },
+            "reset" => {
+                self.reset();
+                Ok("back to the starting stacks".to_owned())
+            }
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+/// Day 7: the file system rebuilt from the terminal session.
+struct Terminal(day7::AppendOnlyFS);
+
+impl Explorer for Terminal {
+    fn help(&self) -> &'static str {
+        "\
+ls [path]         list a directory, the root by default
+du [path]         total size of a file or directory"
+    }
+
+    fn answer(&self, part: u8) -> String {
+        answer::<day7::Day7>(&self.0, part)
+    }
+
+    fn command(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>> {
+        let path = args.first().copied().unwrap_or("/");
+        let result = match name {
+            "ls" => self
+                .0
+                .list(path)
+                .map(|listing| {
+                    listing
+                        .iter()
+                        .map(|entry| match entry {
+                            day7::Listing::Directory { name, size } => {
+                                format!("dir {} ({})", name, size)
+                            }
+                            day7::Listing::File { name, size } => format!("{} {}", size, name),
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                })
+                .ok_or_else(|| format!("no directory `{}`", path)),
+            "du" => self
+                .0
+                .size_of(path)
+                .map(|size| size.to_string())
+                .ok_or_else(|| format!("nothing at `{}`", path)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+/// Day 8: the forest, with its visible trees computed on first use.
+struct Trees {
+    forest: day8::Forest,
+    visible: Option<HashSet<Point>>,
+}
+
+impl Trees {
+    fn tree(&mut self, args: &[&str]) -> Result<String, String> {
+        let position = Point::new(arg(args, 0, "a column")?, arg(args, 1, "a row")?);
+        let height = self
+            .forest
+            .trees()
+            .get(position)
+            .ok_or_else(|| format!("no tree at {}, {}", position.x, position.y))?;
+
+        let forest = &self.forest;
+        let visible = self
+            .visible
+            .get_or_insert_with(|| forest.visible_trees())
+            .contains(&position);
+        let distances: Vec<String> = [
+            ("up", Point::UP),
+            ("left", Point::LEFT),
+            ("down", Point::DOWN),
+            ("right", Point::RIGHT),
+        ]
+        .iter()
+        .map(|(name, step)| {
+            let distance = forest.iter(position, *step).viewing_distance().unwrap_or(0);
+            format!("{} {}", name, distance)
+        })
+        .collect();
+
+        Ok(format!(
+            "height {}, {}, scenic score {} ({})",
+            height,
+            match visible {
+                true => "visible",
+                false => "hidden",
+            },
+            forest.scenic_score(position).unwrap(),
+            distances.join(", ")
+        ))
+    }
+}
+
+impl Explorer for Trees {
+    fn help(&self) -> &'static str {
+        "\
+size              columns and rows of the forest
+tree <x> <y>      height, visibility and scenic score of a tree"
+    }
+
+    fn answer(&self, part: u8) -> String {
+        answer::<day8::Day8>(&self.forest, part)
+    }
+
+    fn command(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>> {
+        let result = match name {
+            "size" => Ok(format!(
+                "{} columns, {} rows",
+                self.forest.columns(),
+                self.forest.rows()
+            )),
+            "tree" => self.tree(args),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+/// Day 10: the CPU part way through the program.
+struct Program {
+    instructions: Vec<day10::Instruction>,
+    cpu: day10::Cpu,
+    next: usize,
+}
+
+impl Program {
+    fn state(&self) -> String {
+        let running = match self.instructions.get(self.next) {
+            Some(day10::Instruction::Noop) => "noop".to_owned(),
+            Some(day10::Instruction::AddX(value)) => {
+                format!("addx {} ({}/2 cycles done)", value, self.cpu.progress())
+            }
+            None => "nothing, the program ended".to_owned(),
+        };
+
+        format!(
+            "cycle {}, X = {}, running {}",
+            self.cpu.cycle(),
+            self.cpu.register_value(),
+            running
+        )
+    }
+
+    fn step(&mut self, cycles: usize) -> String {
+        let mut states = Vec::new();
+        for _ in 0..cycles {
+            let Some(instruction) = self.instructions.get(self.next) else {
+                break;
+            };
+
+            if self.cpu.tick(instruction) {
+                self.next += 1;
+            }
+            states.push(self.state());
+        }
+
+        match states.is_empty() {
+            true => self.state(),
+            false => states.join("\n"),
+        }
+    }
+}
+
+impl Explorer for Program {
+    fn help(&self) -> &'static str {
+        "\
+state             show the cycle, the register and the running instruction
+step [cycles]     run the next cycles, 1 by default
+reset             go back to the first cycle"
+    }
+
+    fn answer(&self, part: u8) -> String {
+        answer::<day10::Day10>(&self.instructions, part)
+    }
+
+    fn command(&mut self, name: &str, args: &[&str]) -> Option<Result<String, String>> {
+        let result = match name {
+            "state" => Ok(self.state()),
+            "step" => optional_arg(args, 0, "a cycle count", 1).map(|cycles| self.step(cycles)),
+            "reset" => {
+                self.cpu = day10::Cpu::new();
+                self.next = 0;
+                Ok(self.state())
+            }
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+/// Parses `input` for `day`, `None` when the day is not solved yet.
+pub fn explorer(day: u8, input: &str) -> Option<Result<Box<dyn Explorer>, Error>> {
+    fn boxed(explorer: impl Explorer + 'static) -> Box<dyn Explorer> {
+        Box::new(explorer)
+    }
+
+    let explorer = match day {
+        5 => day5::Day5::parse(input).map(|procedure| boxed(Crane::new(procedure))),
+        7 => day7::Day7::parse(input).map(|fs| boxed(Terminal(fs))),
+        8 => day8::Day8::parse(input).map(|forest| {
+            boxed(Trees {
+                forest,
+                visible: None,
+            })
+        }),
+        10 => day10::Day10::parse(input).map(|instructions| {
+            boxed(Program {
+                instructions,
+                cpu: day10::Cpu::new(),
+                next: 0,
+            })
+        }),
+        _ => return parsed(day, input),
+    };
+
+    Some(explorer)
+}
+
+macro_rules! days {
+    ($($day:literal => $solution:ty,)*) => {
+        /// Loads a day that has no commands of its own.
+        fn parsed(day: u8, input: &str) -> Option<Result<Box<dyn Explorer>, Error>> {
+            let parsed = match day {
+                $($day => <$solution>::parse(input)
+                    .map(|input| Box::new(Parsed::<$solution>(input)) as Box<dyn Explorer>),)*
+                _ => return None,
+            };
+
+            Some(parsed)
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+}
+
+/// Runs one line of input, `None` when it asks to leave.
+fn execute(explorer: &mut dyn Explorer, line: &str) -> Option<Result<String, String>> {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Some(Ok(String::new()));
+    };
+    let args: Vec<&str> = words.collect();
+
+    let result = match name {
+        "quit" | "exit" => return None,
+        "help" => Ok(match explorer.help() {
+            "" => HELP.to_owned(),
+            help => format!("{}\n{}", help, HELP),
+        }),
+        "part1" => Ok(explorer.answer(1)),
+        "part2" => Ok(explorer.answer(2)),
+        _ => explorer
+            .command(name, &args)
+            .unwrap_or_else(|| Err(format!("unknown command `{}`, try `help`", name))),
+    };
+
+    Some(result)
+}
+
+/// Reads commands from `commands` until it ends or asks to leave, writing a
+/// `dayN> ` prompt before each one.
+pub fn run(
+    day: u8,
+    explorer: &mut dyn Explorer,
+    commands: impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut lines = commands.lines();
+    loop {
+        write!(out, "day{}> ", day)?;
+        out.flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(out)?;
+            return Ok(());
+        };
+
+        match execute(explorer, &line) {
+            Some(Ok(output)) if output.is_empty() => {}
+            Some(Ok(output)) => writeln!(out, "{}", output)?,
+            Some(Err(err)) => writeln!(out, "error: {}", err)?,
+            None => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(day: u8, input: &str) -> Box<dyn Explorer> {
+        explorer(day, input).unwrap().unwrap()
+    }
+
+    fn output(explorer: &mut dyn Explorer, line: &str) -> Result<String, String> {
+        execute(explorer, line).unwrap()
+    }
+
+    #[test]
+    fn any_day_answers() {
+        let mut day1 = load(1, "1000\n2000\n\n4000");
+        assert_eq!(output(day1.as_mut(), "part1"), Ok("4000".to_owned()));
+        assert_eq!(output(day1.as_mut(), "part2"), Ok("7000".to_owned()));
+        assert!(output(day1.as_mut(), "stacks").is_err());
+        assert!(execute(day1.as_mut(), "quit").is_none());
+
+        assert!(explorer(42, "").is_none());
+        assert!(explorer(1, "x").unwrap().is_err());
+    }
+
+    #[test]
+    fn day5_steps_through_the_procedure() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n\
+            move 1 from 2 to 1\nmove 3 from 1 to 3";
+        let mut crane = load(5, input);
+        assert_eq!(output(crane.as_mut(), "stack 1"), Ok("1: ZN".to_owned()));
+        assert_eq!(
+            output(crane.as_mut(), "step"),
+            Ok("1: move 1 from 2 to 1".to_owned())
+        );
+        assert_eq!(output(crane.as_mut(), "stack 1"), Ok("1: ZND".to_owned()));
+        output(crane.as_mut(), "step").unwrap();
+        assert_eq!(output(crane.as_mut(), "stack 3"), Ok("3: PDNZ".to_owned()));
+
+        output(crane.as_mut(), "crane 9001").unwrap();
+        output(crane.as_mut(), "step 5").unwrap();
+        assert_eq!(output(crane.as_mut(), "stack 3"), Ok("3: PZND".to_owned()));
+        assert!(output(crane.as_mut(), "stack 4").is_err());
+    }
+
+    #[test]
+    fn day7_lists_paths() {
+        let mut terminal = load(7, "$ cd /\n$ ls\ndir a\n40000000 b\n$ cd a\n$ ls\n20 c");
+        assert_eq!(
+            output(terminal.as_mut(), "ls"),
+            Ok("dir a (20)\n40000000 b".to_owned())
+        );
+        assert_eq!(output(terminal.as_mut(), "du /a/c"), Ok("20".to_owned()));
+        assert!(output(terminal.as_mut(), "ls /b").is_err());
+    }
+
+    #[test]
+    fn day8_describes_trees() {
+        let mut trees = load(8, "30373\n25512\n65332\n33549\n35390");
+        assert_eq!(
+            output(trees.as_mut(), "tree 2 3"),
+            Ok("height 5, visible, scenic score 8 (up 2, left 2, down 1, right 2)".to_owned())
+        );
+        assert!(output(trees.as_mut(), "tree 5 0").is_err());
+        assert!(output(trees.as_mut(), "tree x 0").is_err());
+    }
+
+    #[test]
+    fn day10_steps_cycles() {
+        let mut program = load(10, "noop\naddx 3\naddx -5");
+        output(program.as_mut(), "step 2").unwrap();
+        assert_eq!(
+            output(program.as_mut(), "state"),
+            Ok("cycle 3, X = 1, running addx 3 (1/2 cycles done)".to_owned())
+        );
+        output(program.as_mut(), "step").unwrap();
+        assert_eq!(
+            output(program.as_mut(), "state"),
+            Ok("cycle 4, X = 4, running addx -5 (0/2 cycles done)".to_owned())
+        );
+        output(program.as_mut(), "step 10").unwrap();
+        assert_eq!(
+            output(program.as_mut(), "state"),
+            Ok("cycle 6, X = -1, running nothing, the program ended".to_owned())
+        );
+    }
+
+    #[test]
+    fn run_prompts_for_every_command() {
+        let mut explorer = load(1, "1");
+        let mut out = Vec::new();
+        run(
+            1,
+            explorer.as_mut(),
+            "part1\nnope\nquit\npart2".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day1> 1\nday1> error: unknown command `nope`, try `help`\nday1> "
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::output::Format;
+use day1::{Elf, Stats};
+use serde::Serialize;
+
+const BAR_WIDTH: usize = 40;
+
+/// The distribution of a set of calories, the totals of every elf, every item
+/// or the items of the `elf` given by its 1-based position. Everything but
+/// the count is null for an empty set.
+#[derive(Serialize)]
+struct StatsRecord<'a> {
+    scope: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    elf: Option<usize>,
+    count: usize,
+    mean: Option<f64>,
+    median: Option<f64>,
+    percent: u8,
+    percentile: Option<u64>,
+    min: Option<u64>,
+    max: Option<u64>,
+}
+
+/// A histogram bucket: the number of elves carrying from `start` to `end`
+/// calories, both included.
+#[derive(Serialize)]
+struct BucketRecord {
+    start: u64,
+    end: u64,
+    elves: usize,
+}
+
+fn optional(value: Option<impl ToString>) -> String {
+    value.map_or("-".to_owned(), |value| value.to_string())
+}
+
+fn print_stats(format: Format, label: &str, record: &StatsRecord) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string(record).unwrap()),
+        Format::Text => println!(
+            "{:<9}  {:>7}  {:>10}  {:>10}  {:>10}  {:>8}  {:>8}",
+            label,
+            record.count,
+            optional(record.mean.map(|mean| format!("{:.1}", mean))),
+            optional(record.median.map(|median| format!("{:.1}", median))),
+            optional(record.percentile),
+            optional(record.min),
+            optional(record.max)
+        ),
+    }
+}
+
+fn record<'a>(scope: &'a str, elf: Option<usize>, stats: &Stats, percent: u8) -> StatsRecord<'a> {
+    StatsRecord {
+        scope,
+        elf,
+        count: stats.count(),
+        mean: stats.mean(),
+        median: stats.median(),
+        percent,
+        percentile: stats.percentile(f64::from(percent)),
+        min: stats.min(),
+        max: stats.max(),
+    }
+}
+
+/// Prints the distribution of the calories carried per elf and of the items,
+/// optionally of the items of every elf, then a histogram of the calories
+/// carried per elf.
+pub fn print_report(format: Format, elves: &[Elf], percent: u8, buckets: usize, per_elf: bool) {
+    if let Format::Text = format {
+        println!(
+            "{:<9}  {:>7}  {:>10}  {:>10}  {:>10}  {:>8}  {:>8}",
+            "",
+            "Count",
+            "Mean",
+            "Median",
+            format!("p{}", percent),
+            "Min",
+            "Max"
+        );
+    }
+
+    let totals = day1::total_stats(elves);
+    print_stats(format, "Elves", &record("elves", None, &totals, percent));
+    let items = day1::item_stats(elves);
+    print_stats(format, "Items", &record("items", None, &items, percent));
+    if per_elf {
+        for (index, elf) in elves.iter().enumerate() {
+            let label = format!("Elf {}", index + 1);
+            print_stats(
+                format,
+                &label,
+                &record("elf", Some(index + 1), &elf.stats(), percent),
+            );
+        }
+    }
+
+    let histogram = totals.histogram(buckets);
+    let widest = histogram.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    if let Format::Text = format {
+        println!("\nCalories per elf");
+    }
+
+    for (range, count) in histogram {
+        match format {
+            Format::Json => {
+                let record = BucketRecord {
+                    start: *range.start(),
+                    end: *range.end(),
+                    elves: count,
+                };
+                println!("{}", serde_json::to_string(&record).unwrap());
+            }
+            Format::Text => println!(
+                "{:>8} to {:>8}  {:>5}  {}",
+                range.start(),
+                range.end(),
+                count,
+                "#".repeat((count * BAR_WIDTH).div_ceil(widest.max(1)))
+            ),
+        }
+    }
+}
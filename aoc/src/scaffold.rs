@@ -0,0 +1,74 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TEMPLATE: &str = include_str!("../template/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../template/lib.rs.in");
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+/// Inserts `line` after the last line of the file matching `is_entry`, so
+/// new days land next to the already registered ones.
+fn register(
+    path: &Path,
+    is_entry: impl Fn(&str) -> bool,
+    line: &str,
+) -> Result<(), Box<dyn Error>> {
+    let content = fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = content.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|current| is_entry(current))
+        .ok_or_else(|| format!("no registered days found in {}", path.display()))?;
+
+    lines.insert(last + 1, line);
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// Creates the `dayN` crate from the template, with an empty input file, and
+/// registers it with the workspace, the runner, the REPL and the WebAssembly
+/// bindings.
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day))?;
+    fs::write(dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day))?;
+    fs::write(dir.join("input.txt"), "")?;
+
+    register(
+        &root.join("Cargo.toml"),
+        |line| line.trim_start().starts_with("\"day"),
+        &format!("    \"day{}\",", day),
+    )?;
+    // Both the runner and the WebAssembly bindings dispatch on the day.
+    for (krate, dispatch) in [("aoc", "src/days.rs"), ("wasm", "src/lib.rs")] {
+        register(
+            &root.join(krate).join("Cargo.toml"),
+            |line| line.starts_with("day"),
+            &format!("day{0} = {{ path = \"../day{0}\" }}", day),
+        )?;
+        register(
+            &root.join(krate).join(dispatch),
+            |line| line.contains("=> day"),
+            &format!("    {0} => day{0}::Day{0},", day),
+        )?;
+    }
+    register(
+        &root.join("aoc").join("src").join("repl.rs"),
+        |line| line.contains("=> day"),
+        &format!("    {0} => day{0}::Day{0},", day),
+    )?;
+
+    Ok(dir)
+}
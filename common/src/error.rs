@@ -0,0 +1,128 @@
+use std::fmt;
+
+/// What is wrong with the token an [`Error`] points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A token that is not one of the values allowed at its position.
+    InvalidToken {
+        /// Description of the allowed values.
+        expected: &'static str,
+    },
+    /// A token that should be a number but failed to parse as one.
+    InvalidNumber,
+    /// A line that ends before a required part was found.
+    MissingToken {
+        /// Description of the missing part.
+        expected: &'static str,
+    },
+    /// A well-formed token that does not make sense in the current state.
+    Invalid {
+        /// Why the token is rejected, read as a prefix of the token.
+        reason: &'static str,
+    },
+    /// A number that makes a running total too large to represent.
+    Overflow {
+        /// What the total counts.
+        total: &'static str,
+    },
+    /// Text that does not follow the keyword pattern of its line.
+    Mismatch {
+        /// The pattern, with `{}` for every captured value.
+        pattern: &'static str,
+    },
+}
+
+/// A parse error pointing at the offending token of the puzzle input.
+///
+/// Lines and columns are 1-based. Parsers working on a single line or token
+/// report positions relative to it, callers move them into place with
+/// [`Error::offset_lines`] and [`Error::offset_columns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// What is wrong with the token.
+    pub kind: ErrorKind,
+    /// 1-based line of the token.
+    pub line: usize,
+    /// 1-based column of the token, in bytes for most parsers.
+    pub column: usize,
+    /// The offending text.
+    pub token: String,
+}
+
+impl Error {
+    /// An error about `token`, placed at the first line and column.
+    pub fn new(kind: ErrorKind, token: &str) -> Self {
+        Self {
+            kind,
+            line: 1,
+            column: 1,
+            token: token.to_owned(),
+        }
+    }
+
+    /// See [`ErrorKind::InvalidToken`].
+    pub fn invalid_token(token: &str, expected: &'static str) -> Self {
+        Self::new(ErrorKind::InvalidToken { expected }, token)
+    }
+
+    /// See [`ErrorKind::InvalidNumber`].
+    pub fn invalid_number(token: &str) -> Self {
+        Self::new(ErrorKind::InvalidNumber, token)
+    }
+
+    /// See [`ErrorKind::MissingToken`].
+    pub fn missing_token(token: &str, expected: &'static str) -> Self {
+        Self::new(ErrorKind::MissingToken { expected }, token)
+    }
+
+    /// See [`ErrorKind::Invalid`].
+    pub fn invalid(token: &str, reason: &'static str) -> Self {
+        Self::new(ErrorKind::Invalid { reason }, token)
+    }
+
+    /// See [`ErrorKind::Overflow`].
+    pub fn overflow(token: &str, total: &'static str) -> Self {
+        Self::new(ErrorKind::Overflow { total }, token)
+    }
+
+    /// See [`ErrorKind::Mismatch`].
+    pub fn mismatch(token: &str, pattern: &'static str) -> Self {
+        Self::new(ErrorKind::Mismatch { pattern }, token)
+    }
+
+    /// Moves the error down by `lines`.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error right by `columns`.
+    pub fn offset_columns(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ErrorKind::InvalidToken { expected } => {
+                write!(f, "expected {}, found `{}`", expected, self.token)
+            }
+            ErrorKind::InvalidNumber => write!(f, "invalid number `{}`", self.token),
+            ErrorKind::MissingToken { expected } => {
+                write!(f, "missing {} in `{}`", expected, self.token)
+            }
+            ErrorKind::Invalid { reason } => write!(f, "{} `{}`", reason, self.token),
+            ErrorKind::Overflow { total } => {
+                write!(f, "{} overflows with `{}`", total, self.token)
+            }
+            ErrorKind::Mismatch { pattern } => {
+                write!(f, "expected `{}`, found `{}`", pattern, self.token)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,283 @@
+use crate::{parse_chars, Error, Point, NEIGHBORS_4, NEIGHBORS_8};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular 2D grid stored row by row, addressed by [`Point`]s with `x`
+/// as the column and `y` as the row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let columns = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != columns) {
+            return None;
+        }
+
+        Some(Self {
+            rows: rows.len(),
+            columns,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map, one row per line, converting each character
+    /// with `parse`. Errors are placed on the offending character.
+    pub fn parse(input: &str, parse: impl Fn(char) -> Result<T, Error>) -> Result<Self, Error> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut columns = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let row = parse_chars(line, &parse).map_err(|err| err.offset_lines(index))?;
+            let width = row.len();
+            cells.extend(row);
+
+            if *columns.get_or_insert(width) != width {
+                return Err(Error::invalid(line, "row length differs in").offset_lines(index));
+            }
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            columns: columns.unwrap_or(0),
+        })
+    }
+
+    /// Number of rows, the height of the grid.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns, the width of the grid.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Whether `position` lies within the grid.
+    pub fn contains(&self, position: Point) -> bool {
+        (0..self.columns as i64).contains(&(position.x as i64))
+            && (0..self.rows as i64).contains(&(position.y as i64))
+    }
+
+    fn index_of(&self, position: Point) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y as usize * self.columns + position.x as usize)
+    }
+
+    /// The cell at `position`, `None` outside the grid.
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.cells.get(self.index_of(position)?)
+    }
+
+    /// The cell at `position` for modification, `None` outside the grid.
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        let index = self.index_of(position)?;
+        self.cells.get_mut(index)
+    }
+
+    /// The in-bounds orthogonal neighbors of `position`.
+    pub fn neighbors4(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS_4
+            .iter()
+            .map(move |step| position + *step)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbors of `position`.
+    pub fn neighbors8(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS_8
+            .iter()
+            .map(move |step| position + *step)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let columns = self.columns as i32;
+        (0..self.rows as i32).flat_map(move |y| (0..columns).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell of the grid with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The cells of a row from left to right, `.rev()` walks right to left.
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = (Point, &T)> {
+        let cells = match row < self.rows {
+            true => &self.cells[row * self.columns..(row + 1) * self.columns],
+            false => &[],
+        };
+
+        cells
+            .iter()
+            .enumerate()
+            .map(move |(column, value)| (Point::new(column as i32, row as i32), value))
+    }
+
+    /// The cells of a column from top to bottom, `.rev()` walks bottom to top.
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = (Point, &T)> {
+        let rows = match column < self.columns {
+            true => self.rows,
+            false => 0,
+        };
+
+        (0..rows).map(move |row| {
+            let position = Point::new(column as i32, row as i32);
+            (position, &self[position])
+        })
+    }
+
+    /// Walks from `start` (inclusive) in `step` increments until leaving the
+    /// grid.
+    ///
+    /// # Panics
+    ///
+    /// When `step` is [`Point::ZERO`], which would never leave the grid.
+    pub fn ray(&self, start: Point, step: Point) -> Ray<'_, T> {
+        assert!(step != Point::ZERO, "a ray needs a non-zero step");
+        Ray {
+            grid: self,
+            current: start,
+            step,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        self.get(position).expect("position outside of grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        self.get_mut(position).expect("position outside of grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            for (_, value) in self.row(row) {
+                write!(f, "{}", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterator over the cells along a straight line, see [`Grid::ray`].
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    current: Point,
+    step: Point,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.current;
+        let value = self.grid.get(position)?;
+        self.current += self.step;
+        Some((position, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::invalid_token(&c.to_string(), "a digit"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse("12\n3x", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::invalid_token(&c.to_string(), "a digit"))
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        assert!(Grid::parse("12\n3", Ok).is_err());
+    }
+
+    #[test]
+    fn bounds() {
+        let grid = digits("12\n34");
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&4));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(Point::new(1, 2)).count(), 5);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits("123\n456\n789");
+        let values = |iter: &mut dyn Iterator<Item = (Point, &u32)>| {
+            iter.map(|(_, value)| *value).collect::<Vec<u32>>()
+        };
+
+        assert_eq!(values(&mut grid.row(1)), vec![4, 5, 6]);
+        assert_eq!(values(&mut grid.row(1).rev()), vec![6, 5, 4]);
+        assert_eq!(values(&mut grid.column(2)), vec![3, 6, 9]);
+        assert_eq!(values(&mut grid.column(2).rev()), vec![9, 6, 3]);
+        assert_eq!(values(&mut grid.row(3)), vec![]);
+        assert_eq!(
+            values(&mut grid.ray(Point::new(0, 2), Point::new(1, -1))),
+            vec![7, 5, 3]
+        );
+        assert_eq!(
+            values(&mut grid.ray(Point::new(0, 3), Point::new(1, -1))),
+            vec![]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn ray_needs_a_step() {
+        digits("12").ray(Point::ZERO, Point::ZERO);
+    }
+}
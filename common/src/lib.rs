@@ -0,0 +1,37 @@
+//! Shared building blocks for the daily puzzle crates: the [`Solution`]
+//! trait, positioned parse errors, parsing helpers and 2D grids.
+#![warn(missing_docs)]
+
+mod error;
+mod grid;
+mod parse;
+mod point;
+
+pub use error::{Error, ErrorKind};
+pub use grid::{Grid, Ray};
+pub use parse::{
+    blocks, map_lines, normalize_input, offset_in, parse_blocks, parse_chars, parse_in,
+    parse_lines, parse_number, parse_pair, parse_separated, pattern, split_pair, MapLines,
+};
+pub use point::{Point, NEIGHBORS_4, NEIGHBORS_8};
+
+use std::fmt::Display;
+
+/// A puzzle solver, split into parsing the input and solving both parts on
+/// the parsed model.
+pub trait Solution {
+    /// The model produced from the raw puzzle input.
+    type Input;
+    /// The answer to the first part.
+    type Answer1: Display;
+    /// The answer to the second part.
+    type Answer2: Display;
+
+    /// Builds the model from the puzzle input, pointing at the offending
+    /// token when it is malformed.
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    /// Solves the first part.
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    /// Solves the second part.
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
@@ -0,0 +1,286 @@
+use crate::Error;
+use std::{iter::Enumerate, str::Lines};
+
+/// Byte offset of `part` within `whole`, where `part` is a subslice of
+/// `whole` (as returned by `split_once`, `split` and friends).
+pub fn offset_in(whole: &str, part: &str) -> usize {
+    part.as_ptr() as usize - whole.as_ptr() as usize
+}
+
+/// Converts `\r\n` line endings to `\n` and strips trailing whitespace from
+/// every line as well as blank lines at the end of `input`.
+pub fn normalize_input(input: &str) -> String {
+    let mut normalized = input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n");
+    normalized.truncate(normalized.trim_end().len());
+    normalized
+}
+
+/// Parses a number, reporting the token on failure.
+pub fn parse_number<T: std::str::FromStr>(token: &str) -> Result<T, Error> {
+    token.parse().map_err(|_| Error::invalid_number(token))
+}
+
+/// Parses `part` of `whole`, placing errors at the column `part` starts at.
+pub fn parse_in<'a, T>(
+    whole: &str,
+    part: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, Error>,
+) -> Result<T, Error> {
+    parse(part).map_err(|err| err.offset_columns(offset_in(whole, part)))
+}
+
+/// Parses every character of `line`, placing errors on the character.
+pub fn parse_chars<T>(
+    line: &str,
+    parse: impl Fn(char) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    line.chars()
+        .enumerate()
+        .map(|(column, c)| parse(c).map_err(|err| err.offset_columns(column)))
+        .collect()
+}
+
+/// Lazily parses every line of `input`, see [`map_lines`].
+pub struct MapLines<'a, F> {
+    lines: Enumerate<Lines<'a>>,
+    parse: F,
+}
+
+impl<'a, T, F> Iterator for MapLines<'a, F>
+where
+    F: FnMut(&'a str) -> Result<T, Error>,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, line) = self.lines.next()?;
+        Some((self.parse)(line).map_err(|err| err.offset_lines(index)))
+    }
+}
+
+/// Parses the lines of `input` one at a time, placing errors on the line they
+/// came from. Useful to fold the input without collecting it first.
+pub fn map_lines<'a, T, F>(input: &'a str, parse: F) -> MapLines<'a, F>
+where
+    F: FnMut(&'a str) -> Result<T, Error>,
+{
+    MapLines {
+        lines: input.lines().enumerate(),
+        parse,
+    }
+}
+
+/// Parses every line of `input`, placing errors on the line they came from.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    map_lines(input, parse).collect()
+}
+
+/// Splits `input` into blocks of lines separated by blank lines, yielding the
+/// 0-based line index each block starts at along with the block.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.lines().enumerate().peekable();
+    let is_blank = |line: &str| line.trim().is_empty();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| is_blank(line)).is_some() {}
+
+        let (index, first) = lines.next()?;
+        let mut last = first;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !is_blank(line)) {
+            last = line;
+        }
+
+        let start = offset_in(input, first);
+        let end = offset_in(input, last) + last.len();
+        Some((index, &input[start..end]))
+    })
+}
+
+/// Parses every blank-line-separated block of `input`, placing errors on the
+/// line they came from.
+pub fn parse_blocks<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    blocks(input)
+        .map(|(index, block)| parse(block).map_err(|err| err.offset_lines(index)))
+        .collect()
+}
+
+/// Splits `input` at the first `separator`, reporting `expected` as missing
+/// when there is none.
+pub fn split_pair<'a>(
+    input: &'a str,
+    separator: &str,
+    expected: &'static str,
+) -> Result<(&'a str, &'a str), Error> {
+    input
+        .split_once(separator)
+        .ok_or_else(|| Error::missing_token(input, expected))
+}
+
+/// Parses both sides of the first `separator` in `input`, e.g. `2-4` or
+/// `A Y`. `expected` describes the second value when the separator is missing.
+pub fn parse_pair<'a, A, B>(
+    input: &'a str,
+    separator: &str,
+    expected: &'static str,
+    parse_first: impl FnOnce(&'a str) -> Result<A, Error>,
+    parse_second: impl FnOnce(&'a str) -> Result<B, Error>,
+) -> Result<(A, B), Error> {
+    let (first, second) = split_pair(input, separator, expected)?;
+    Ok((parse_first(first)?, parse_in(input, second, parse_second)?))
+}
+
+/// Parses every `separator` separated value of `input`, e.g. `1,2,3`.
+pub fn parse_separated<'a, T>(
+    input: &'a str,
+    separator: &str,
+    mut parse: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    input
+        .split(separator)
+        .map(|part| parse_in(input, part, &mut parse))
+        .collect()
+}
+
+/// Matches `input` against a keyword `pattern` such as
+/// `"move {} from {} to {}"`, returning the text captured by each `{}`. A
+/// capture runs up to the next literal part of the pattern, or the end of the
+/// line for a trailing `{}`.
+///
+/// # Panics
+///
+/// When `pattern` does not have exactly `N` placeholders.
+pub fn pattern<'a, const N: usize>(
+    input: &'a str,
+    pattern: &'static str,
+) -> Result<[&'a str; N], Error> {
+    let mismatch =
+        |rest: &str| Error::mismatch(rest, pattern).offset_columns(offset_in(input, rest));
+    let mut literals = pattern.split("{}");
+    let mut rest = input;
+    let prefix = literals.next().unwrap();
+    rest = rest.strip_prefix(prefix).ok_or_else(|| mismatch(rest))?;
+
+    let mut fields = [""; N];
+    for field in fields.iter_mut() {
+        let literal = literals
+            .next()
+            .expect("pattern has fewer `{}` than captures");
+        let end = match literal.is_empty() {
+            true => rest.len(),
+            false => rest.find(literal).ok_or_else(|| mismatch(rest))?,
+        };
+
+        *field = &rest[..end];
+        rest = &rest[end + literal.len()..];
+    }
+
+    assert!(
+        literals.next().is_none(),
+        "pattern has more `{{}}` than captures"
+    );
+    match rest.is_empty() {
+        true => Ok(fields),
+        false => Err(mismatch(rest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalized_input() {
+        assert_eq!(normalize_input("1\r\n2 \r\n\r\n3\r\n\r\n"), "1\n2\n\n3");
+        assert_eq!(normalize_input("  [D]\n1\n"), "  [D]\n1");
+        assert_eq!(normalize_input("\n \n"), "");
+    }
+
+    #[test]
+    fn lines_are_placed() {
+        let numbers = parse_lines("1\n2\n3", parse_number::<u32>).unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let err = parse_lines("1\n2\nx", parse_number::<u32>).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        let sum: Result<u32, Error> = map_lines("1\n2\n3", parse_number::<u32>).sum();
+        assert_eq!(sum, Ok(6));
+    }
+
+    #[test]
+    fn blocks_skip_blank_lines() {
+        let input = "1\n2\n\n3\n\n\n4\n";
+        let found: Vec<(usize, &str)> = blocks(input).collect();
+        assert_eq!(found, vec![(0, "1\n2"), (3, "3"), (6, "4")]);
+        assert_eq!(blocks("\n\n").count(), 0);
+
+        let sums = parse_blocks(input, |block| map_lines(block, parse_number::<u32>).sum());
+        assert_eq!(sums, Ok(vec![3, 3, 4]));
+        let err =
+            parse_blocks("1\n\n2\nx", |block| parse_lines(block, parse_number::<u32>)).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (4, "x"));
+    }
+
+    #[test]
+    fn pairs_and_lists() {
+        let range = |input| parse_pair(input, "-", "an end", parse_number::<u32>, parse_number);
+        assert_eq!(range("2-4"), Ok((2, 4)));
+
+        let err = range("2-x").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (3, "x"));
+
+        let err = range("24").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: missing an end in `24`");
+
+        let list = parse_separated("1, 22, 3", ", ", parse_number::<u32>);
+        assert_eq!(list, Ok(vec![1, 22, 3]));
+
+        let err = parse_separated("1, 22, x", ", ", parse_number::<u32>).unwrap_err();
+        assert_eq!(err.column, 8);
+    }
+
+    #[test]
+    fn chars_are_placed() {
+        let digits = |c: char| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::invalid_token("", "a digit"))
+        };
+        assert_eq!(parse_chars("123", digits), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_chars("12x", digits).unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn keyword_patterns() {
+        let fields = pattern("move 1 from 2 to 3", "move {} from {} to {}");
+        assert_eq!(fields, Ok(["1", "2", "3"]));
+        assert_eq!(pattern("$ cd a b", "$ cd {}"), Ok(["a b"]));
+        assert_eq!(pattern("noop", "noop"), Ok([]));
+
+        let err = pattern::<3>("move 1 to 3", "move {} from {} to {}").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (6, "1 to 3"));
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: expected `move {} from {} to {}`, found `1 to 3`"
+        );
+
+        let err = pattern::<1>("addx 3 4", "addx {} ").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (8, "4"));
+        assert!(pattern::<1>("subx 3", "addx {}").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn pattern_needs_matching_captures() {
+        let _ = pattern::<2>("a b", "a {}");
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A 2D point or vector in screen coordinates: `x` grows to the right and `y`
+/// grows downwards, so `y` is the row and `x` the column of a [`Grid`].
+///
+/// [`Grid`]: crate::Grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    /// The column, growing to the right.
+    pub x: i32,
+    /// The row, growing downwards.
+    pub y: i32,
+}
+
+/// The four orthogonal directions, clockwise from up.
+pub const NEIGHBORS_4: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+/// The eight orthogonal and diagonal directions, clockwise from up.
+pub const NEIGHBORS_8: [Point; 8] = [
+    Point::UP,
+    Point::new(1, -1),
+    Point::RIGHT,
+    Point::new(1, 1),
+    Point::DOWN,
+    Point::new(-1, 1),
+    Point::LEFT,
+    Point::new(-1, -1),
+];
+
+impl Point {
+    /// The origin.
+    pub const ZERO: Point = Point::new(0, 0);
+    /// One step up, towards the previous row.
+    pub const UP: Point = Point::new(0, -1);
+    /// One step down, towards the next row.
+    pub const DOWN: Point = Point::new(0, 1);
+    /// One step left, towards the previous column.
+    pub const LEFT: Point = Point::new(-1, 0);
+    /// One step right, towards the next column.
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// The point at column `x` and row `y`.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Number of orthogonal steps between the two points.
+    pub fn manhattan(self, other: Point) -> u32 {
+        let delta = other - self;
+        delta.x.unsigned_abs() + delta.y.unsigned_abs()
+    }
+
+    /// Number of king moves between the two points, `1` for any neighbor.
+    pub fn chebyshev(self, other: Point) -> u32 {
+        let delta = other - self;
+        delta.x.unsigned_abs().max(delta.y.unsigned_abs())
+    }
+
+    /// The unit step (including diagonals) pointing in this direction.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut point = Point::new(2, 3);
+        point += Point::RIGHT * 2;
+        point -= Point::UP;
+        assert_eq!(point, Point::new(4, 4));
+        assert_eq!(-point, Point::new(-4, -4));
+        assert_eq!(point - Point::new(1, 1), Point::new(3, 3));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 1);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.chebyshev(a), 0);
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Point::new(-5, 2).signum(), Point::new(-1, 1));
+        assert_eq!(Point::new(0, -7).signum(), Point::UP);
+        assert_eq!(Point::ZERO.signum(), Point::ZERO);
+    }
+
+    #[test]
+    fn directions_are_neighbors() {
+        for direction in NEIGHBORS_8 {
+            assert_eq!(Point::ZERO.chebyshev(direction), 1);
+        }
+        for direction in NEIGHBORS_4 {
+            assert_eq!(Point::ZERO.manhattan(direction), 1);
+        }
+    }
+}
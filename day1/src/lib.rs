@@ -0,0 +1,653 @@
+//! Day 1: Calorie Counting.
+//!
+//! Reads the inventory of each elf, one blank-line separated block of item
+//! calories per elf, and looks for the elves carrying the most calories.
+//! Totals are checked, so inventories too large to add up are rejected
+//! instead of wrapping around.
+#![warn(missing_docs)]
+
+use common::{map_lines, parse_blocks, parse_number, Error, Solution};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{self, BufRead},
+    ops::RangeInclusive,
+};
+
+/// Parses the calories of an item, rejecting negative values explicitly
+/// rather than as invalid numbers.
+pub fn parse_calories(token: &str) -> Result<u64, Error> {
+    match token.starts_with('-') {
+        true => Err(Error::invalid(token, "negative calories")),
+        false => parse_number(token),
+    }
+}
+
+/// The calories of every item an elf carries, in the order they were listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    items: Vec<u64>,
+    total: u64,
+}
+
+impl Elf {
+    /// An elf carrying `items`, `None` when they add up to more than a `u64`
+    /// holds.
+    pub fn new(items: Vec<u64>) -> Option<Self> {
+        let total = items
+            .iter()
+            .try_fold(0u64, |total, &calories| total.checked_add(calories))?;
+
+        Some(Self { items, total })
+    }
+
+    /// Adds the item on `line`, reporting when the total overflows.
+    fn push(&mut self, line: &str) -> Result<(), Error> {
+        let calories = parse_calories(line)?;
+        self.total = self
+            .total
+            .checked_add(calories)
+            .ok_or_else(|| Error::overflow(line, "calories of an elf"))?;
+        self.items.push(calories);
+        Ok(())
+    }
+
+    /// Calories of every item.
+    pub fn items(&self) -> &[u64] {
+        &self.items
+    }
+
+    /// Calories of all items together.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Distribution of the calories of the items.
+    pub fn stats(&self) -> Stats {
+        Stats::new(self.items.iter().copied())
+    }
+}
+
+impl TryFrom<&str> for Elf {
+    type Error = Error;
+
+    fn try_from(block: &str) -> Result<Self, Self::Error> {
+        let mut elf = Elf {
+            items: Vec::new(),
+            total: 0,
+        };
+        map_lines(block, |line| elf.push(line)).collect::<Result<(), Error>>()?;
+
+        Ok(elf)
+    }
+}
+
+/// Distribution of calorie values, such as the items of an elf or the totals
+/// of every elf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    sorted: Vec<u64>,
+}
+
+impl Stats {
+    /// Distribution of `values`, in any order.
+    pub fn new(values: impl IntoIterator<Item = u64>) -> Self {
+        let mut sorted: Vec<u64> = values.into_iter().collect();
+        sorted.sort_unstable();
+        Self { sorted }
+    }
+
+    /// Number of values.
+    pub fn count(&self) -> usize {
+        self.sorted.len()
+    }
+
+    /// Smallest value.
+    pub fn min(&self) -> Option<u64> {
+        self.sorted.first().copied()
+    }
+
+    /// Largest value.
+    pub fn max(&self) -> Option<u64> {
+        self.sorted.last().copied()
+    }
+
+    /// Average value.
+    pub fn mean(&self) -> Option<f64> {
+        let sum: u128 = self.sorted.iter().map(|&value| u128::from(value)).sum();
+        (!self.sorted.is_empty()).then(|| sum as f64 / self.count() as f64)
+    }
+
+    /// Middle value, or the average of the two middle values for an even
+    /// count.
+    pub fn median(&self) -> Option<f64> {
+        if self.sorted.is_empty() {
+            return None;
+        }
+
+        let middle = self.count() / 2;
+        match self.count() % 2 {
+            0 => Some((self.sorted[middle - 1] as f64 + self.sorted[middle] as f64) / 2.0),
+            _ => Some(self.sorted[middle] as f64),
+        }
+    }
+
+    /// Smallest value that at least `percent` percent of the values are less
+    /// than or equal to (nearest rank).
+    ///
+    /// # Panics
+    ///
+    /// When `percent` is not between 0 and 100.
+    pub fn percentile(&self, percent: f64) -> Option<u64> {
+        assert!(
+            (0.0..=100.0).contains(&percent),
+            "percentile out of range: {}",
+            percent
+        );
+        let rank = (percent / 100.0 * self.count() as f64).ceil() as usize;
+        self.sorted.get(rank.max(1) - 1).copied()
+    }
+
+    /// Number of values in each of at most `buckets` ranges of equal width
+    /// covering every value from the smallest to the largest.
+    ///
+    /// # Panics
+    ///
+    /// When `buckets` is 0.
+    pub fn histogram(&self, buckets: usize) -> Vec<(RangeInclusive<u64>, usize)> {
+        assert!(buckets > 0, "a histogram needs at least one bucket");
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return Vec::new();
+        };
+
+        // Widths are computed in u128, the span of `0..=u64::MAX` does not
+        // fit in a u64.
+        let span = u128::from(max - min) + 1;
+        let width = span.div_ceil(buckets as u128);
+        let bound = |offset: u128| (u128::from(min) + offset).min(u128::from(u64::MAX)) as u64;
+        let mut histogram: Vec<(RangeInclusive<u64>, usize)> = (0..span.div_ceil(width))
+            .map(|bucket| (bound(bucket * width)..=bound((bucket + 1) * width - 1), 0))
+            .collect();
+        for &value in self.sorted.iter() {
+            histogram[(u128::from(value - min) / width) as usize].1 += 1;
+        }
+
+        histogram
+    }
+}
+
+/// Distribution of the calories carried by every elf.
+pub fn total_stats(elves: &[Elf]) -> Stats {
+    Stats::new(elves.iter().map(Elf::total))
+}
+
+/// Distribution of the calories of every item, whichever elf carries it.
+pub fn item_stats(elves: &[Elf]) -> Stats {
+    Stats::new(elves.iter().flat_map(|elf| elf.items.iter().copied()))
+}
+
+/// The elves carrying the most calories seen so far, keeping at most `count`
+/// of them.
+#[derive(Debug, Clone)]
+struct TopElves {
+    count: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopElves {
+    fn new(count: usize) -> Self {
+        Self {
+            count,
+            // The heap grows with the elves seen rather than `count`, which
+            // comes straight from the command line.
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn push(&mut self, index: usize, calories: u64) {
+        self.heap.push(Reverse((calories, Reverse(index))));
+        if self.heap.len() > self.count {
+            self.heap.pop();
+        }
+    }
+
+    fn to_vec(&self) -> Vec<(usize, u64)> {
+        self.heap
+            .clone()
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| (index, calories))
+            .collect()
+    }
+}
+
+/// The `count` elves carrying the most calories as `(index, calories)`, given
+/// the calories carried by every elf. Most first, with ties going to the
+/// earlier elf. Only `count` elves are kept around instead of sorting all of
+/// them.
+pub fn top_elves(totals: impl IntoIterator<Item = u64>, count: usize) -> Vec<(usize, u64)> {
+    let mut top = TopElves::new(count);
+    for (index, calories) in totals.into_iter().enumerate() {
+        top.push(index, calories);
+    }
+
+    top.to_vec()
+}
+
+/// Running statistics over an inventory fed one item at a time, keeping only
+/// the elves carrying the most calories. See [`stream`].
+#[derive(Debug, Clone)]
+pub struct Tally {
+    elves: usize,
+    items: usize,
+    total: u64,
+    smallest: Option<u64>,
+    largest: Option<u64>,
+    current: Option<u64>,
+    top: TopElves,
+}
+
+impl Tally {
+    /// An empty tally remembering the `top` elves carrying the most calories.
+    pub fn new(top: usize) -> Self {
+        Self {
+            elves: 0,
+            items: 0,
+            total: 0,
+            smallest: None,
+            largest: None,
+            current: None,
+            top: TopElves::new(top),
+        }
+    }
+
+    /// Adds an item to the current elf, failing when the elf would carry
+    /// more calories than a `u64` holds.
+    pub fn add_item(&mut self, calories: u64) -> Result<(), Error> {
+        let current = self
+            .current
+            .unwrap_or(0)
+            .checked_add(calories)
+            .ok_or_else(|| Error::overflow(&calories.to_string(), "calories of an elf"))?;
+
+        self.items += 1;
+        self.current = Some(current);
+        Ok(())
+    }
+
+    /// Finishes the current elf, if it carries any item, failing when all
+    /// elves together would carry more calories than a `u64` holds.
+    pub fn end_elf(&mut self) -> Result<(), Error> {
+        let Some(calories) = self.current else {
+            return Ok(());
+        };
+
+        self.total = self
+            .total
+            .checked_add(calories)
+            .ok_or_else(|| Error::overflow(&calories.to_string(), "calories of all elves"))?;
+        self.current = None;
+        self.top.push(self.elves, calories);
+        self.elves += 1;
+        self.smallest = Some(self.smallest.map_or(calories, |min| min.min(calories)));
+        self.largest = Some(self.largest.map_or(calories, |max| max.max(calories)));
+        Ok(())
+    }
+
+    /// Number of finished elves.
+    pub fn elves(&self) -> usize {
+        self.elves
+    }
+
+    /// Number of items, including those of the current elf.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Calories carried by all finished elves.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Average calories per finished elf, `None` before the first one.
+    pub fn mean(&self) -> Option<f64> {
+        (self.elves > 0).then(|| self.total as f64 / self.elves as f64)
+    }
+
+    /// Fewest calories carried by a finished elf.
+    pub fn smallest(&self) -> Option<u64> {
+        self.smallest
+    }
+
+    /// Most calories carried by a finished elf.
+    pub fn largest(&self) -> Option<u64> {
+        self.largest
+    }
+
+    /// The finished elves carrying the most calories, as in [`top_elves`].
+    pub fn top(&self) -> Vec<(usize, u64)> {
+        self.top.to_vec()
+    }
+}
+
+/// Reads an inventory one line at a time, in constant memory whatever its
+/// size, remembering the `top` elves carrying the most calories. Invalid
+/// calories and overflowing totals are reported as
+/// [`io::ErrorKind::InvalidData`] wrapping the placed [`Error`].
+pub fn stream(mut reader: impl BufRead, top: usize) -> io::Result<Tally> {
+    let invalid = |err: Error, index: usize| {
+        io::Error::new(io::ErrorKind::InvalidData, err.offset_lines(index))
+    };
+
+    let mut tally = Tally::new(top);
+    let mut line = String::new();
+    let (mut index, mut elf_start) = (0, 0);
+    while reader.read_line(&mut line)? > 0 {
+        let item = line.trim_end();
+        match item.trim_start().is_empty() {
+            true => tally.end_elf().map_err(|err| invalid(err, elf_start))?,
+            false => {
+                if tally.current.is_none() {
+                    elf_start = index;
+                }
+
+                let calories = parse_calories(item).map_err(|err| invalid(err, index))?;
+                tally
+                    .add_item(calories)
+                    .map_err(|err| invalid(err, index))?;
+            }
+        }
+
+        line.clear();
+        index += 1;
+    }
+
+    tally.end_elf().map_err(|err| invalid(err, elf_start))?;
+    Ok(tally)
+}
+
+/// Solver for day 1, parsing the input into the inventory of every [`Elf`].
+/// Parsing fails when all elves together carry more calories than a `u64`
+/// holds, so any sum of elves fits.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Elf>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut total: u64 = 0;
+        let elves = parse_blocks(input, |block| {
+            let elf = Elf::try_from(block)?;
+            let first = block.lines().next().unwrap_or(block);
+            total = total
+                .checked_add(elf.total)
+                .ok_or_else(|| Error::overflow(first, "calories of all elves"))?;
+
+            Ok(elf)
+        })?;
+
+        match elves.is_empty() {
+            true => Err(Error::missing_token(input, "an elf inventory")),
+            false => Ok(elves),
+        }
+    }
+
+    fn part1(elves: &Self::Input) -> u64 {
+        top_elves(elves.iter().map(Elf::total), 1)
+            .first()
+            .unwrap()
+            .1
+    }
+
+    fn part2(elves: &Self::Input) -> u64 {
+        top_elves(elves.iter().map(Elf::total), 3)
+            .iter()
+            .map(|(_, calories)| calories)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ErrorKind;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn example() {
+        let elves = Day1::parse(EXAMPLE).unwrap();
+        let totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+        assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(elves[2].items(), &[5000, 6000]);
+        assert_eq!(Day1::part1(&elves), 24000);
+        assert_eq!(Day1::part2(&elves), 45000);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let elves = Day1::parse(&input).unwrap();
+            assert_eq!(Day1::part1(&elves), 24000);
+            assert_eq!(Day1::part2(&elves), 45000);
+        }
+    }
+
+    #[test]
+    fn fewer_than_three_elves() {
+        let elves = Day1::parse("100\n\n200").unwrap();
+        assert_eq!(Day1::part1(&elves), 200);
+        assert_eq!(Day1::part2(&elves), 300);
+    }
+
+    #[test]
+    fn top_elves_with_indices() {
+        let elves = Day1::parse(EXAMPLE).unwrap();
+        let totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+        assert_eq!(top_elves(totals.clone(), 2), vec![(3, 24000), (2, 11000)]);
+        assert_eq!(top_elves(totals.clone(), 10).len(), 5);
+        assert!(top_elves(totals.clone(), 0).is_empty());
+        assert_eq!(top_elves(totals, usize::MAX).len(), 5);
+
+        let ties = [5, 9, 5, 1, 9, 5];
+        assert_eq!(top_elves(ties, 3), vec![(1, 9), (4, 9), (0, 5)]);
+        let mut sorted: Vec<(usize, u64)> = ties.iter().copied().enumerate().collect();
+        sorted.sort_by_key(|&(index, calories)| (Reverse(calories), index));
+        assert_eq!(top_elves(ties, ties.len()), sorted);
+    }
+
+    #[test]
+    fn statistics() {
+        let elves = Day1::parse(EXAMPLE).unwrap();
+        let totals = total_stats(&elves);
+        assert_eq!(
+            (totals.count(), totals.min(), totals.max()),
+            (5, Some(4000), Some(24000))
+        );
+        assert_eq!(
+            (totals.mean(), totals.median()),
+            (Some(11000.0), Some(10000.0))
+        );
+        assert_eq!(totals.percentile(0.0), Some(4000));
+        assert_eq!(totals.percentile(40.0), Some(6000));
+        assert_eq!(totals.percentile(41.0), Some(10000));
+        assert_eq!(totals.percentile(100.0), Some(24000));
+
+        let items = item_stats(&elves);
+        assert_eq!((items.count(), items.median()), (10, Some(5500.0)));
+        assert_eq!(elves[3].stats().median(), Some(8000.0));
+
+        assert_eq!(
+            totals.histogram(4),
+            vec![
+                (4000..=9000, 2),
+                (9001..=14001, 2),
+                (14002..=19002, 0),
+                (19003..=24003, 1)
+            ]
+        );
+        assert_eq!(
+            Stats::new([3, 4]).histogram(10),
+            vec![(3..=3, 1), (4..=4, 1)]
+        );
+
+        let empty = Stats::new([]);
+        assert_eq!(
+            (empty.mean(), empty.median(), empty.percentile(50.0)),
+            (None, None, None)
+        );
+        assert!(empty.histogram(3).is_empty());
+    }
+
+    #[test]
+    fn streamed_tally() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [EXAMPLE.to_owned(), format!("\n\n{}\r\n\r\n", crlf)] {
+            let tally = stream(input.as_bytes(), 3).unwrap();
+            assert_eq!(
+                (tally.elves(), tally.items(), tally.total()),
+                (5, 10, 55000)
+            );
+            assert_eq!(tally.mean(), Some(11000.0));
+            assert_eq!(
+                (tally.smallest(), tally.largest()),
+                (Some(4000), Some(24000))
+            );
+            assert_eq!(tally.top(), vec![(3, 24000), (2, 11000), (4, 10000)]);
+        }
+
+        let tally = stream("".as_bytes(), 3).unwrap();
+        assert_eq!(
+            (tally.elves(), tally.mean(), tally.largest()),
+            (0, None, None)
+        );
+    }
+
+    #[test]
+    fn streamed_invalid_calories() {
+        let err = stream("100\n\n2oo\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "line 3, column 1: invalid number `2oo`");
+    }
+
+    #[test]
+    fn no_elves() {
+        for input in ["", "\n \n"] {
+            let err = Day1::parse(input).unwrap_err();
+            assert_eq!(
+                err.kind,
+                ErrorKind::MissingToken {
+                    expected: "an elf inventory"
+                }
+            );
+            assert_eq!((err.line, err.column), (1, 1));
+        }
+    }
+
+    #[test]
+    fn invalid_calories() {
+        let err = Day1::parse("100\n\n2oo").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(err.token, "2oo");
+    }
+
+    #[test]
+    fn negative_calories() {
+        let err = Day1::parse("100\n-5").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: negative calories `-5`");
+        assert_eq!(
+            err.kind,
+            ErrorKind::Invalid {
+                reason: "negative calories"
+            }
+        );
+
+        let err = stream("100\n\n-0".as_bytes(), 3).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: negative calories `-0`");
+    }
+
+    #[test]
+    fn largest_totals() {
+        let max = u64::MAX.to_string();
+        let elves = Day1::parse(&format!("{}\n0", max)).unwrap();
+        assert_eq!(
+            (Day1::part1(&elves), Day1::part2(&elves)),
+            (u64::MAX, u64::MAX)
+        );
+
+        let half = (u64::MAX / 2).to_string();
+        let elves = Day1::parse(&format!("{0}\n\n{0}\n\n1", half)).unwrap();
+        assert_eq!(Day1::part2(&elves), u64::MAX);
+        assert_eq!(total_stats(&elves).mean(), Some(u64::MAX as f64 / 3.0));
+
+        let stats = Stats::new([0, u64::MAX]);
+        let histogram = stats.histogram(3);
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(
+            histogram.last(),
+            Some(&(12297829382473034412..=u64::MAX, 1))
+        );
+        assert_eq!(
+            Stats::new([u64::MAX; 2]).histogram(2),
+            vec![(u64::MAX..=u64::MAX, 2)]
+        );
+    }
+
+    #[test]
+    fn overflowing_totals() {
+        let max = u64::MAX.to_string();
+        let err = Day1::parse(&format!("1\n\n{}\n1", max)).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (4, "1"));
+        assert_eq!(
+            err.kind,
+            ErrorKind::Overflow {
+                total: "calories of an elf"
+            }
+        );
+        assert_eq!(Elf::new(vec![u64::MAX, 1]), None);
+
+        let err = Day1::parse(&format!("7\n\n{}\n\n5\n6", max)).unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (3, max.as_str()));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "line 3, column 1: calories of all elves overflows with `{}`",
+                max
+            )
+        );
+
+        let err = Day1::parse("18446744073709551616").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidNumber);
+
+        let err = stream(format!("{}\n1", max).as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: calories of an elf overflows with `1`"
+        );
+        let err = stream(format!("2\n\n\n{0}\n\n3", max).as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "line 4, column 1: calories of all elves overflows with `{}`",
+                max
+            )
+        );
+    }
+}
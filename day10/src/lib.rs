@@ -0,0 +1,402 @@
+//! Day 10: Cathode-Ray Tube.
+//!
+//! Runs a tiny CPU program, sampling its register for signal strengths and
+//! drawing the CRT image it produces.
+#![warn(missing_docs)]
+
+use common::{parse_in, parse_lines, parse_number, Error, Solution};
+use std::collections::HashSet;
+
+/// A CPU instruction, one per line of the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// `noop`, takes one cycle and does nothing.
+    Noop,
+    /// `addx <n>`, takes two cycles, then adds `n` to the register.
+    AddX(i32),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value == "noop" {
+            return Ok(Instruction::Noop);
+        }
+
+        let (command, param) = value
+            .split_once(' ')
+            .ok_or_else(|| Error::invalid_token(value, "`noop` or `addx <n>`"))?;
+        match command {
+            "addx" => Ok(Instruction::AddX(parse_in(value, param, parse_number)?)),
+            _ => Err(Error::invalid_token(command, "`noop` or `addx <n>`")),
+        }
+    }
+}
+
+impl Instruction {
+    /// Number of cycles the instruction takes to complete.
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::AddX(_) => 2,
+        }
+    }
+}
+
+/// The CPU state between cycles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu {
+    register_value: i32,
+    cycle: usize,
+    progress: usize,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self {
+            register_value: 1,
+            cycle: 1,
+            progress: 0,
+        }
+    }
+}
+
+impl Cpu {
+    /// A CPU about to start the first cycle, with the register set to 1.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value of the `X` register.
+    pub fn register_value(&self) -> i32 {
+        self.register_value
+    }
+
+    /// The cycle about to start, counting from 1.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// Number of cycles already spent on the running instruction.
+    pub fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// Runs a single cycle of `instruction`, returning whether it completed.
+    /// The same instruction has to be passed until it does.
+    pub fn tick(&mut self, instruction: &Instruction) -> bool {
+        self.cycle += 1;
+        self.progress += 1;
+        if self.progress < instruction.cycles() {
+            return false;
+        }
+
+        if let Instruction::AddX(value) = instruction {
+            self.register_value += value;
+        }
+        self.progress = 0;
+        true
+    }
+
+    /// Runs the remaining cycles of `instruction`.
+    pub fn execute(&mut self, instruction: &Instruction) {
+        while !self.tick(instruction) {}
+    }
+}
+
+/// The `(cycle, register value)` during every cycle of running `program`.
+pub fn cycles(program: &[Instruction]) -> impl Iterator<Item = (usize, i32)> + '_ {
+    let mut cpu = Cpu::new();
+    let mut program = program.iter().peekable();
+    std::iter::from_fn(move || {
+        let instruction = program.peek()?;
+        let during = (cpu.cycle, cpu.register_value);
+        if cpu.tick(instruction) {
+            program.next();
+        }
+
+        Some(during)
+    })
+}
+
+/// Solver for day 10, parsing the input into the program. Parsing fails when
+/// the register would leave the range of an `i32`.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut register: i32 = 1;
+        parse_lines(input, |line| {
+            let instruction = Instruction::try_from(line)?;
+            if let Instruction::AddX(value) = instruction {
+                register = register
+                    .checked_add(value)
+                    .ok_or_else(|| Error::overflow(line, "register"))?;
+            }
+
+            Ok(instruction)
+        })
+    }
+
+    fn part1(instructions: &Self::Input) -> i64 {
+        let checkpoints: HashSet<usize> = HashSet::from([20, 60, 100, 140, 180, 220]);
+
+        cycles(instructions)
+            .filter(|(cycle, _)| checkpoints.contains(cycle))
+            .map(|(cycle, register)| cycle as i64 * i64::from(register))
+            .sum()
+    }
+
+    fn part2(instructions: &Self::Input) -> String {
+        let mut screen = String::new();
+        for (cycle, register) in cycles(instructions) {
+            let position = ((cycle - 1) % 40) as i32;
+            let visible = position.abs_diff(register) <= 1;
+
+            if position == 0 && cycle > 1 {
+                screen.push('\n')
+            }
+
+            if visible {
+                screen.push('#');
+            } else {
+                screen.push(' ');
+            }
+        }
+
+        screen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    const EXAMPLE_SCREEN: &str = "\
+##  ##  ##  ##  ##  ##  ##  ##  ##  ##  
+###   ###   ###   ###   ###   ###   ### 
+####    ####    ####    ####    ####    
+#####     #####     #####     #####     
+######      ######      ######      ####
+#######       #######       #######     ";
+
+    #[test]
+    fn example() {
+        let instructions = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&instructions), 13140);
+        assert_eq!(Day10::part2(&instructions), EXAMPLE_SCREEN);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let instructions = Day10::parse(&input).unwrap();
+            assert_eq!(Day10::part1(&instructions), 13140);
+            assert_eq!(Day10::part2(&instructions), EXAMPLE_SCREEN);
+        }
+    }
+
+    #[test]
+    fn execute() {
+        let mut cpu = Cpu::new();
+        cpu.execute(&Instruction::Noop);
+        assert_eq!((cpu.cycle, cpu.register_value), (2, 1));
+
+        cpu.execute(&Instruction::AddX(-4));
+        assert_eq!((cpu.cycle, cpu.register_value), (4, -3));
+    }
+
+    #[test]
+    fn tick() {
+        let mut cpu = Cpu::new();
+        assert!(!cpu.tick(&Instruction::AddX(3)));
+        assert_eq!((cpu.cycle, cpu.register_value, cpu.progress), (2, 1, 1));
+        assert!(cpu.tick(&Instruction::AddX(3)));
+        assert_eq!((cpu.cycle, cpu.register_value, cpu.progress), (3, 4, 0));
+        assert!(cpu.tick(&Instruction::Noop));
+        assert_eq!((cpu.cycle, cpu.register_value), (4, 4));
+    }
+
+    #[test]
+    fn cycles_report_register_during_cycle() {
+        let program = Day10::parse("noop\naddx 3\naddx -5").unwrap();
+        let during: Vec<(usize, i32)> = cycles(&program).collect();
+        assert_eq!(during, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let err = Day10::parse("noop\nmulx 3").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.token, "mulx");
+    }
+
+    #[test]
+    fn register_overflow() {
+        let err = Day10::parse("addx 2147483646\nnoop\naddx 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: register overflows with `addx 1`"
+        );
+
+        let program = Day10::parse("addx -2147483647\naddx -2\naddx 2147483647").unwrap();
+        assert!(Day10::part2(&program).starts_with("##  "));
+
+        let program = Day10::parse(&format!("addx 2147483646{}", "\nnoop".repeat(18))).unwrap();
+        assert_eq!(Day10::part1(&program), 20 * 2147483647);
+    }
+}
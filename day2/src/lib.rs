@@ -0,0 +1,468 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! Scores a strategy guide of rounds, reading the second column either as
+//! the pick to play or as the outcome to aim for. Scores and advice come from
+//! [`Rules`], which also describe variants with more moves such as
+//! rock-paper-scissors-lizard-Spock.
+#![warn(missing_docs)]
+
+use common::{parse_in, parse_lines, parse_separated, split_pair, Error, Solution};
+use std::{str::FromStr, sync::OnceLock};
+
+/// The rules of rock-paper-scissors, see [`Rules`].
+pub const ROCK_PAPER_SCISSORS: &str = "\
+Rock: Scissors
+Paper: Rock
+Scissors: Paper";
+
+/// The rules of rock-paper-scissors-lizard-Spock, see [`Rules`].
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "\
+Rock: Scissors, Lizard
+Paper: Rock, Spock
+Scissors: Paper, Lizard
+Spock: Scissors, Rock
+Lizard: Spock, Paper";
+
+/// One of the moves of a set of [`Rules`], by 0-based position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move(pub usize);
+
+/// The moves of a rock-paper-scissors variant and which moves each one beats.
+///
+/// A move is worth its 1-based position, plus 6 points for a win, 3 for a
+/// draw and 0 for a loss. Two moves where neither beats the other draw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    beats: Vec<Vec<bool>>,
+}
+
+impl TryFrom<&str> for Rules {
+    type Error = Error;
+
+    /// Parses one `<move>: <beaten>, <beaten>` line per move, in order of
+    /// worth. A move may beat nothing, as in `<move>:`.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut names: Vec<&str> = Vec::new();
+        let lines = parse_lines(input, |line| {
+            let (name, beaten) = split_pair(line, ":", "the moves it beats")?;
+            if names.contains(&name) {
+                return Err(Error::invalid(name, "duplicate move"));
+            }
+
+            names.push(name);
+            Ok((line, beaten.trim_start()))
+        })?;
+
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (winner, (line, beaten)) in lines.into_iter().enumerate() {
+            if beaten.is_empty() {
+                continue;
+            }
+
+            let losers = parse_in(line, beaten, |beaten| {
+                parse_separated(beaten, ", ", |name| {
+                    let loser = names
+                        .iter()
+                        .position(|known| *known == name)
+                        .ok_or_else(|| Error::invalid_token(name, "a move"))?;
+
+                    match (loser == winner, beats[loser][winner]) {
+                        (true, _) => Err(Error::invalid(name, "move beats itself")),
+                        (_, true) => Err(Error::invalid(name, "already beaten by")),
+                        _ => Ok(loser),
+                    }
+                })
+            })
+            .map_err(|err| err.offset_lines(winner))?;
+
+            losers
+                .into_iter()
+                .for_each(|loser| beats[winner][loser] = true);
+        }
+
+        Ok(Rules {
+            names: names.into_iter().map(str::to_owned).collect(),
+            beats,
+        })
+    }
+}
+
+impl Rules {
+    /// Balanced rules over an odd number of moves, where every move beats
+    /// the moves an odd number of places before it, wrapping around. Three
+    /// moves give rock-paper-scissors, and five in the order of
+    /// [`ROCK_PAPER_SCISSORS_LIZARD_SPOCK`] give that variant.
+    ///
+    /// # Panics
+    ///
+    /// When the number of moves is even.
+    pub fn cyclic(names: &[&str]) -> Self {
+        let count = names.len();
+        assert!(count % 2 == 1, "cyclic rules need an odd number of moves");
+
+        Rules {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            beats: (0..count)
+                .map(|winner| {
+                    (0..count)
+                        .map(|loser| (winner + count - loser) % count % 2 == 1)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Every move, in order of worth.
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.names.len()).map(Move)
+    }
+
+    /// Name of `move_`.
+    ///
+    /// # Panics
+    ///
+    /// When `move_` is not one of these rules' moves, as for every method
+    /// taking a [`Move`].
+    pub fn name(&self, move_: Move) -> &str {
+        &self.names[move_.0]
+    }
+
+    /// The move called `name`.
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.names.iter().position(|known| known == name).map(Move)
+    }
+
+    /// Points for playing `move_`, whatever the outcome.
+    pub fn worth(&self, move_: Move) -> i32 {
+        assert!(move_.0 < self.names.len(), "unknown move {:?}", move_);
+        move_.0 as i32 + 1
+    }
+
+    /// The outcome of playing `mine` against `theirs`.
+    pub fn outcome(&self, mine: Move, theirs: Move) -> MatchAdvice {
+        match (self.beats[mine.0][theirs.0], self.beats[theirs.0][mine.0]) {
+            (true, _) => MatchAdvice::Win,
+            (_, true) => MatchAdvice::Lose,
+            _ => MatchAdvice::Draw,
+        }
+    }
+
+    /// Total points for playing `mine` against `theirs`.
+    pub fn score(&self, mine: Move, theirs: Move) -> i32 {
+        self.worth(mine) + self.outcome(mine, theirs).points()
+    }
+
+    /// The least worth move to play against `theirs` for the `advice`d
+    /// outcome, `None` when no move gives it.
+    pub fn respond(&self, theirs: Move, advice: MatchAdvice) -> Option<Move> {
+        self.moves()
+            .find(|&mine| self.outcome(mine, theirs) == advice)
+    }
+}
+
+fn rock_paper_scissors() -> &'static Rules {
+    static RULES: OnceLock<Rules> = OnceLock::new();
+    RULES.get_or_init(|| Rules::try_from(ROCK_PAPER_SCISSORS).unwrap())
+}
+
+/// The outcome the strategy guide asks for, decoded from `X`, `Y` and `Z`,
+/// or the outcome of a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchAdvice {
+    /// Win the round.
+    Win,
+    /// Lose the round.
+    Lose,
+    /// End the round in a draw.
+    Draw,
+}
+
+impl MatchAdvice {
+    /// Points for the outcome: 6 for a win, 3 for a draw and 0 for a loss.
+    pub fn points(&self) -> i32 {
+        match self {
+            MatchAdvice::Win => 6,
+            MatchAdvice::Draw => 3,
+            MatchAdvice::Lose => 0,
+        }
+    }
+}
+
+impl FromStr for MatchAdvice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(MatchAdvice::Lose),
+            "Y" => Ok(MatchAdvice::Draw),
+            "Z" => Ok(MatchAdvice::Win),
+            _ => Err(Error::invalid_token(s, "one of X, Y, Z")),
+        }
+    }
+}
+
+/// A shape played in a round, decoded from `A`, `B`, `C` or `X`, `Y`, `Z`.
+/// Each is the [`Move`] at the same position in [`ROCK_PAPER_SCISSORS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pick {
+    /// Beats scissors, worth 1 point.
+    Rock,
+    /// Beats rock, worth 2 points.
+    Paper,
+    /// Beats paper, worth 3 points.
+    Scissors,
+}
+
+impl FromStr for Pick {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Pick::Rock),
+            "B" => Ok(Pick::Paper),
+            "C" => Ok(Pick::Scissors),
+
+            "X" => Ok(Pick::Rock),
+            "Y" => Ok(Pick::Paper),
+            "Z" => Ok(Pick::Scissors),
+
+            _ => Err(Error::invalid_token(s, "one of A, B, C, X, Y, Z")),
+        }
+    }
+}
+
+impl From<Pick> for Move {
+    fn from(pick: Pick) -> Self {
+        Move(pick as usize)
+    }
+}
+
+impl Pick {
+    const ALL: [Pick; 3] = [Pick::Rock, Pick::Paper, Pick::Scissors];
+
+    /// Points for playing this shape, whatever the outcome.
+    pub fn worth(&self) -> i32 {
+        rock_paper_scissors().worth((*self).into())
+    }
+
+    /// Outcome points for playing this shape against `other`: 6 for a win, 3
+    /// for a draw and 0 for a loss.
+    pub fn score_against(&self, other: &Pick) -> i32 {
+        rock_paper_scissors()
+            .outcome((*self).into(), (*other).into())
+            .points()
+    }
+
+    /// The shape to play against this one to get the `advice`d outcome.
+    pub fn pick_against_for_advice(&self, advice: &MatchAdvice) -> Pick {
+        let response = rock_paper_scissors().respond((*self).into(), *advice);
+        Pick::ALL[response.unwrap().0]
+    }
+}
+
+/// Total score under `rules` of `(opponent, response)` matches from the
+/// responder's side.
+pub fn score_matches(rules: &Rules, matches: &[(Move, Move)]) -> i32 {
+    matches
+        .iter()
+        .map(|&(opponent, response)| rules.score(response, opponent))
+        .sum()
+}
+
+/// A line of the strategy guide. The second column is decoded both as a
+/// pick (part 1) and as match advice (part 2).
+#[derive(Debug)]
+pub struct Round {
+    /// What the opponent plays.
+    pub opponent: Pick,
+    /// The second column read as the shape to play.
+    pub response: Pick,
+    /// The second column read as the outcome to aim for.
+    pub advice: MatchAdvice,
+}
+
+/// Solver for day 2, see [`Solution`].
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |match_input| {
+            let (opponent, response) = split_pair(match_input, " ", "a second pick")?;
+
+            Ok(Round {
+                opponent: Pick::from_str(opponent)?,
+                response: parse_in(match_input, response, Pick::from_str)?,
+                advice: parse_in(match_input, response, MatchAdvice::from_str)?,
+            })
+        })
+    }
+
+    fn part1(rounds: &Self::Input) -> i32 {
+        let matches: Vec<(Move, Move)> = rounds
+            .iter()
+            .map(|round| (round.opponent.into(), round.response.into()))
+            .collect();
+
+        score_matches(rock_paper_scissors(), &matches)
+    }
+
+    fn part2(rounds: &Self::Input) -> i32 {
+        let rules = rock_paper_scissors();
+        let matches: Vec<(Move, Move)> = rounds
+            .iter()
+            .map(|round| {
+                let opponent = round.opponent.into();
+                (opponent, rules.respond(opponent, round.advice).unwrap())
+            })
+            .collect();
+
+        score_matches(rules, &matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn example() {
+        let rounds = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&rounds), 15);
+        assert_eq!(Day2::part2(&rounds), 12);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let rounds = Day2::parse(&input).unwrap();
+            assert_eq!(Day2::part1(&rounds), 15);
+            assert_eq!(Day2::part2(&rounds), 12);
+        }
+    }
+
+    #[test]
+    fn score_against() {
+        let picks = [Pick::Rock, Pick::Paper, Pick::Scissors];
+        let expected = [[3, 0, 6], [6, 3, 0], [0, 6, 3]];
+
+        for (pick, scores) in picks.iter().zip(expected) {
+            for (other, score) in picks.iter().zip(scores) {
+                assert_eq!(pick.score_against(other), score);
+            }
+        }
+    }
+
+    #[test]
+    fn pick_against_for_advice() {
+        for pick in [Pick::Rock, Pick::Paper, Pick::Scissors] {
+            let win = pick.pick_against_for_advice(&MatchAdvice::Win);
+            let draw = pick.pick_against_for_advice(&MatchAdvice::Draw);
+            let lose = pick.pick_against_for_advice(&MatchAdvice::Lose);
+
+            assert_eq!(win.score_against(&pick), 6);
+            assert_eq!(draw.score_against(&pick), 3);
+            assert_eq!(lose.score_against(&pick), 0);
+        }
+    }
+
+    #[test]
+    fn rules_derive_outcomes() {
+        let rules = Rules::try_from(ROCK_PAPER_SCISSORS).unwrap();
+        assert_eq!(rules, Rules::cyclic(&["Rock", "Paper", "Scissors"]));
+        for pick in Pick::ALL {
+            assert_eq!(rules.name(pick.into()), format!("{:?}", pick));
+            assert_eq!(rules.worth(pick.into()), pick.worth());
+        }
+
+        let rules = Rules::try_from(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap();
+        let names = ["Rock", "Paper", "Scissors", "Spock", "Lizard"];
+        assert_eq!(rules, Rules::cyclic(&names));
+
+        let [rock, spock, lizard] =
+            ["Rock", "Spock", "Lizard"].map(|name| rules.find(name).unwrap());
+        assert_eq!(rules.outcome(lizard, spock), MatchAdvice::Win);
+        assert_eq!(rules.outcome(rock, spock), MatchAdvice::Lose);
+        assert_eq!(rules.score(lizard, rock), 5);
+        assert_eq!(rules.score(spock, rock), 10);
+        assert_eq!(rules.respond(rock, MatchAdvice::Win), Some(Move(1)));
+
+        for theirs in rules.moves() {
+            let wins = rules
+                .moves()
+                .filter(|&mine| rules.outcome(mine, theirs) == MatchAdvice::Win);
+            assert_eq!(wins.count(), 2);
+            for advice in [MatchAdvice::Win, MatchAdvice::Draw, MatchAdvice::Lose] {
+                let mine = rules.respond(theirs, advice).unwrap();
+                assert_eq!(rules.outcome(mine, theirs), advice);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_rules() {
+        let rules = Rules::try_from("King: Queen, Pawn\nQueen: Pawn\nPawn:").unwrap();
+        assert_eq!(rules.respond(Move(0), MatchAdvice::Win), None);
+        assert_eq!(rules.respond(Move(2), MatchAdvice::Lose), None);
+        assert_eq!(rules.respond(Move(2), MatchAdvice::Win), Some(Move(0)));
+
+        let rules = Rules::try_from("Stone: Shears\nShears:\nWater:").unwrap();
+        let [stone, shears, water] =
+            ["Stone", "Shears", "Water"].map(|name| rules.find(name).unwrap());
+        assert_eq!(rules.outcome(water, stone), MatchAdvice::Draw);
+        assert_eq!(rules.outcome(shears, stone), MatchAdvice::Lose);
+        assert_eq!(rules.respond(stone, MatchAdvice::Draw), Some(stone));
+        assert_eq!(rules.respond(water, MatchAdvice::Draw), Some(stone));
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let err =
+            Rules::try_from("Rock: Scissors\nScissors: Paper, Lizard\nPaper: Rock").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 18: expected a move, found `Lizard`"
+        );
+
+        let err = Rules::try_from("Fire: Ice\nIce: Wind\nWind: Ice").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 7: already beaten by `Ice`");
+
+        let err = Rules::try_from("Rock: Rock").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: move beats itself `Rock`"
+        );
+
+        let err = Rules::try_from("Rock: Paper\nRock: Paper").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: duplicate move `Rock`");
+
+        let err = Rules::try_from("Rock").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: missing the moves it beats in `Rock`"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn cyclic_rules_need_odd_moves() {
+        Rules::cyclic(&["Odd", "Even"]);
+    }
+
+    #[test]
+    fn invalid_pick() {
+        let err = Day2::parse("A Y\nB W").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.token, "W");
+    }
+}
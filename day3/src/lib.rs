@@ -0,0 +1,290 @@
+//! Day 3: Rucksack Reorganization.
+//!
+//! Finds the item type packed in both compartments of every rucksack and the
+//! badge shared by every group of three elves.
+#![warn(missing_docs)]
+
+use common::{parse_chars, parse_lines, Error, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// An item type, parsed from its letter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Item {
+    priority: u32,
+}
+
+impl Item {
+    /// Priority of the item type: 1 to 26 for `a` to `z`, 27 to 52 for `A`
+    /// to `Z`.
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+impl TryFrom<char> for Item {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let char_code = value as u32;
+        match char_code {
+            97..=122 => Ok(Self {
+                priority: char_code - 96,
+            }),
+            65..=90 => Ok(Self {
+                priority: char_code - 65 + 27,
+            }),
+            _ => Err(Error::invalid_token(
+                &value.to_string(),
+                "an item between a-z or A-Z",
+            )),
+        }
+    }
+}
+
+/// Priority of the only item type found in every one of `lists`, `None`
+/// when there is no such item type or more than one.
+fn only_shared(lists: &[&[Item]]) -> Option<u32> {
+    let (first, rest) = lists.split_first()?;
+    let sets: Vec<HashSet<&Item>> = rest
+        .iter()
+        .map(|list| HashSet::from_iter(list.iter()))
+        .collect();
+    let mut shared = first
+        .iter()
+        .filter(|item| sets.iter().all(|set| set.contains(item)))
+        .map(Item::priority);
+
+    let priority = shared.next()?;
+    shared.all(|other| other == priority).then_some(priority)
+}
+
+/// Priority of the item found in both compartments of a backpack.
+///
+/// # Panics
+///
+/// When the compartments do not share exactly one item type, which
+/// [`Day3::parse`] rules out.
+pub fn misplaced_priority(backpack: &[Item]) -> u32 {
+    let (compartment_a, compartment_b) = backpack.split_at(backpack.len() / 2);
+    only_shared(&[compartment_a, compartment_b]).expect("compartments share exactly one item type")
+}
+
+/// Priority of the only item carried by every member of a group.
+///
+/// # Panics
+///
+/// When the members do not have exactly one item type in common, which
+/// [`Day3::parse`] rules out for every group of three.
+pub fn badge_priority(members: &[Vec<Item>]) -> u32 {
+    let members: Vec<&[Item]> = members.iter().map(Vec::as_slice).collect();
+    only_shared(&members).expect("members share exactly one item type")
+}
+
+/// Parses a backpack, checking that its compartments hold the same number of
+/// items and share exactly one item type.
+fn parse_backpack(line: &str) -> Result<Vec<Item>, Error> {
+    let backpack = parse_chars(line, Item::try_from)?;
+    if backpack.len() % 2 != 0 {
+        return Err(Error::invalid(line, "odd number of items in"));
+    }
+
+    let (compartment_a, compartment_b) = backpack.split_at(backpack.len() / 2);
+    match only_shared(&[compartment_a, compartment_b]) {
+        Some(_) => Ok(backpack),
+        None => Err(Error::invalid(
+            line,
+            "not exactly one item type in both compartments of",
+        )),
+    }
+}
+
+/// Solver for day 3, parsing the input into the items of every backpack.
+/// Parsing checks that every backpack has exactly one misplaced item type
+/// and that the backpacks form groups of three with exactly one badge each.
+pub struct Day3;
+
+impl Day3 {
+    /// Sum of the misplaced priorities on the current thread, what
+    /// [`Solution::part1`] does without the `parallel` feature.
+    pub fn part1_sequential(backpacks: &[Vec<Item>]) -> u32 {
+        backpacks
+            .iter()
+            .map(|backpack| misplaced_priority(backpack))
+            .sum()
+    }
+
+    /// Sum of the badge priorities on the current thread, what
+    /// [`Solution::part2`] does without the `parallel` feature.
+    pub fn part2_sequential(backpacks: &[Vec<Item>]) -> u32 {
+        backpacks.chunks(3).map(badge_priority).sum()
+    }
+}
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<Item>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let backpacks = parse_lines(input, parse_backpack)?;
+        let lines: Vec<&str> = input.lines().collect();
+        for (group, members) in backpacks.chunks(3).enumerate() {
+            let first = group * 3;
+            let members: Vec<&[Item]> = members.iter().map(Vec::as_slice).collect();
+            if members.len() < 3 {
+                return Err(Error::invalid(
+                    lines[first],
+                    "incomplete group of three starting with",
+                )
+                .offset_lines(first));
+            }
+
+            if only_shared(&members).is_none() {
+                return Err(Error::invalid(
+                    lines[first],
+                    "not exactly one badge in the group starting with",
+                )
+                .offset_lines(first));
+            }
+        }
+
+        Ok(backpacks)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part1(backpacks: &Self::Input) -> u32 {
+        Self::part1_sequential(backpacks)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn part1(backpacks: &Self::Input) -> u32 {
+        backpacks
+            .par_iter()
+            .map(|backpack| misplaced_priority(backpack))
+            .sum()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part2(backpacks: &Self::Input) -> u32 {
+        Self::part2_sequential(backpacks)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn part2(backpacks: &Self::Input) -> u32 {
+        backpacks.par_chunks(3).map(badge_priority).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn example() {
+        let backpacks = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&backpacks), 157);
+        assert_eq!(Day3::part2(&backpacks), 70);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let backpacks = Day3::parse(&input).unwrap();
+            assert_eq!(Day3::part1(&backpacks), 157);
+            assert_eq!(Day3::part2(&backpacks), 70);
+        }
+    }
+
+    #[test]
+    fn item_priority() {
+        let priority = |c: char| Item::try_from(c).unwrap().priority;
+        assert_eq!(priority('a'), 1);
+        assert_eq!(priority('z'), 26);
+        assert_eq!(priority('A'), 27);
+        assert_eq!(priority('Z'), 52);
+        assert!(Item::try_from('1').is_err());
+    }
+
+    #[test]
+    fn invalid_backpacks() {
+        let err = Day3::parse("abcdef\nghijkl\nmnopqr").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: not exactly one item type in both compartments of `abcdef`"
+        );
+
+        let err = Day3::parse("aa\naab").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: odd number of items in `aab`"
+        );
+
+        let err = Day3::parse("abab").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: not exactly one item type in both compartments of `abab`"
+        );
+    }
+
+    #[test]
+    fn invalid_groups() {
+        let err = Day3::parse(&format!("{}\naa\nbb", EXAMPLE)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 1: incomplete group of three starting with `aa`"
+        );
+
+        let err = Day3::parse("aa\nbb\naa").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: not exactly one badge in the group starting with `aa`"
+        );
+
+        let err = Day3::parse("abcb\nabdb\nabeb").unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+
+    /// Groups of three backpacks built from disjoint pools of letters, so
+    /// every backpack has exactly one misplaced item and every group one
+    /// badge.
+    fn generated(groups: usize) -> String {
+        let letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut lines = Vec::new();
+        for group in 0..groups {
+            let letter = |index: usize| letters[(group * 7 + index) % letters.len()];
+            for member in 0..3 {
+                let pool = 4 + member * 8;
+                let length = (group + member) % 5 + 2;
+                let compartment_a: String = [letter(1 + member), letter(0)]
+                    .into_iter()
+                    .chain((0..length - 1).map(|i| letter(pool + i % 4)))
+                    .collect();
+                let compartment_b: String = [letter(1 + member)]
+                    .into_iter()
+                    .chain((0..length).map(|i| letter(pool + 4 + i % 4)))
+                    .collect();
+                lines.push(compartment_a + &compartment_b);
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let backpacks = Day3::parse(&generated(500)).unwrap();
+        assert_eq!(Day3::part1(&backpacks), Day3::part1_sequential(&backpacks));
+        assert_eq!(Day3::part2(&backpacks), Day3::part2_sequential(&backpacks));
+    }
+}
@@ -0,0 +1,178 @@
+//! Day 4: Camp Cleanup.
+//!
+//! Counts the pairs of section assignments where one range contains the other
+//! or where they overlap at all.
+#![warn(missing_docs)]
+
+use common::{parse_lines, parse_number, parse_pair, Error, Solution};
+
+/// An inclusive range of section IDs such as `2-4`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl TryFrom<&str> for Range {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (start, end) = parse_pair(
+            value,
+            "-",
+            "a `-` separated range",
+            parse_number,
+            parse_number,
+        )?;
+        Ok(Range { start, end })
+    }
+}
+
+impl Range {
+    /// The sections from `start` to `end`, both included.
+    pub fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
+    /// First section of the range.
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Last section of the range.
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Whether every section of this range is also in `other`.
+    pub fn contains(&self, other: &Range) -> bool {
+        self.start >= other.start && self.end <= other.end
+    }
+
+    /// Whether the two ranges share at least one section.
+    pub fn intersects(&self, other: &Range) -> bool {
+        self.start <= other.end && self.end >= other.start
+    }
+}
+
+/// Solver for day 4, parsing the input into pairs of ranges.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Range, Range)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            parse_pair(
+                line,
+                ",",
+                "a second range",
+                Range::try_from,
+                Range::try_from,
+            )
+        })
+    }
+
+    fn part1(ranges: &Self::Input) -> usize {
+        ranges
+            .iter()
+            .filter(|(a, b)| a.contains(b) || b.contains(a))
+            .count()
+    }
+
+    fn part2(ranges: &Self::Input) -> usize {
+        ranges.iter().filter(|(a, b)| a.intersects(b)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    fn range(start: u32, end: u32) -> Range {
+        Range { start, end }
+    }
+
+    #[test]
+    fn example() {
+        let ranges = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&ranges), 2);
+        assert_eq!(Day4::part2(&ranges), 4);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let ranges = Day4::parse(&input).unwrap();
+            assert_eq!(Day4::part1(&ranges), 2);
+            assert_eq!(Day4::part2(&ranges), 4);
+        }
+    }
+
+    #[test]
+    fn contains_is_within_other() {
+        assert!(range(3, 7).contains(&range(2, 8)));
+        assert!(!range(2, 8).contains(&range(3, 7)));
+        assert!(range(6, 6).contains(&range(4, 6)));
+        assert!(range(4, 6).contains(&range(4, 6)));
+        assert!(!range(4, 7).contains(&range(5, 7)));
+    }
+
+    #[test]
+    fn intersects() {
+        assert!(range(5, 7).intersects(&range(7, 9)));
+        assert!(range(7, 9).intersects(&range(5, 7)));
+        assert!(range(2, 8).intersects(&range(3, 7)));
+        assert!(!range(2, 3).intersects(&range(4, 5)));
+        assert!(!range(4, 5).intersects(&range(2, 3)));
+    }
+
+    #[test]
+    fn invalid_range() {
+        let err = Day4::parse("2-4,6-8\n2-3,4_5").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.token, "4_5");
+    }
+
+    fn sections(range: &Range) -> HashSet<u32> {
+        (range.start..=range.end).collect()
+    }
+
+    fn any_range() -> impl Strategy<Value = Range> {
+        (0..100u32, 0..20u32).prop_map(|(start, length)| Range {
+            start,
+            end: start + length,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn intersects_is_symmetric(a in any_range(), b in any_range()) {
+            prop_assert_eq!(a.intersects(&b), b.intersects(&a));
+        }
+
+        #[test]
+        fn intersects_matches_section_overlap(a in any_range(), b in any_range()) {
+            let overlap = !sections(&a).is_disjoint(&sections(&b));
+            prop_assert_eq!(a.intersects(&b), overlap);
+        }
+
+        #[test]
+        fn contains_matches_section_subset(a in any_range(), b in any_range()) {
+            prop_assert_eq!(a.contains(&b), sections(&a).is_subset(&sections(&b)));
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Day 5: Supply Stacks.
+//!
+//! Replays a crane's rearrangement procedure on stacks of crates, moving
+//! crates one at a time (part 1) or several at once (part 2).
+#![warn(missing_docs)]
+
+use common::{blocks, parse_in, parse_lines, parse_number, pattern, Error, Solution};
+
+/// Stacks of crates, parsed from the drawing above the procedure.
+#[derive(Clone, Debug)]
+pub struct Cargo {
+    stacks: Vec<Vec<char>>,
+}
+
+impl TryFrom<&str> for Cargo {
+    type Error = Error;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let lines: Vec<&str> = input.lines().collect();
+        let (cargo_info, cargo_lines) = lines
+            .split_last()
+            .ok_or_else(|| Error::missing_token(input, "a stack number line"))?;
+        let stack_count = cargo_info.split_whitespace().count();
+
+        let mut stacks: Vec<Vec<char>> = Vec::with_capacity(stack_count);
+        for _ in 0..stack_count {
+            stacks.push(Vec::new());
+        }
+
+        for (index, line) in cargo_lines.iter().enumerate().rev() {
+            let chars: Vec<char> = line.chars().collect();
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                let item = chars.get(i * 4 + 1).copied().unwrap_or(' ');
+                if item == ' ' {
+                    continue;
+                }
+
+                if !item.is_ascii_alphabetic() {
+                    return Err(Error::invalid_token(&item.to_string(), "a crate letter")
+                        .offset_lines(index)
+                        .offset_columns(i * 4 + 1));
+                }
+
+                stack.push(item);
+            }
+        }
+
+        Ok(Cargo { stacks })
+    }
+}
+
+/// A `move <count> from <source> to <destination>` step. Stacks are
+/// numbered from 1 as in the drawing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Number of crates to move.
+    pub count: usize,
+    /// Stack the crates are taken from.
+    pub source: usize,
+    /// Stack the crates are put on.
+    pub destination: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = Error;
+
+    fn try_from(instruction: &str) -> Result<Self, Self::Error> {
+        let [count, source, destination] = pattern(instruction, "move {} from {} to {}")?;
+
+        Ok(Instruction {
+            count: parse_in(instruction, count, parse_number)?,
+            source: parse_in(instruction, source, parse_number)?,
+            destination: parse_in(instruction, destination, parse_number)?,
+        })
+    }
+}
+
+impl Cargo {
+    /// The stacks from left to right, each from bottom to top.
+    pub fn stacks(&self) -> &[Vec<char>] {
+        &self.stacks
+    }
+
+    /// Moves the crates of `instruction`. With `reverse_picked` they are moved
+    /// one at a time, reversing their order, otherwise all at once.
+    ///
+    /// # Panics
+    ///
+    /// When a stack does not exist or holds fewer than `count` crates.
+    pub fn apply_instruction(mut self, instruction: &Instruction, reverse_picked: bool) -> Self {
+        let source_size = self.stacks[instruction.source - 1].len();
+        let mut picked =
+            self.stacks[instruction.source - 1].split_off(source_size - instruction.count);
+
+        if reverse_picked {
+            picked.reverse();
+        }
+
+        self.stacks[instruction.destination - 1].append(&mut picked);
+        self
+    }
+
+    /// The crate on top of every stack, from left to right, with a space
+    /// for an empty stack.
+    pub fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().copied().unwrap_or(' '))
+            .collect::<String>()
+    }
+}
+
+/// The starting stacks together with the steps to rearrange them.
+#[derive(Debug)]
+pub struct Procedure {
+    cargo: Cargo,
+    instructions: Vec<Instruction>,
+}
+
+impl Procedure {
+    /// The stacks before any step ran.
+    pub fn cargo(&self) -> &Cargo {
+        &self.cargo
+    }
+
+    /// The rearrangement steps, in order. Every step refers to an existing
+    /// stack and takes no more crates than its source stack holds by then.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Runs every step on a copy of the starting stacks, see
+    /// [`Cargo::apply_instruction`].
+    pub fn run(&self, reverse_picked: bool) -> Cargo {
+        let mut cargo = self.cargo.clone();
+        for instruction in self.instructions.iter() {
+            cargo = cargo.apply_instruction(instruction, reverse_picked);
+        }
+
+        cargo
+    }
+}
+
+/// Solver for day 5, parsing the input into a [`Procedure`].
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut blocks = blocks(input);
+        let (cargo_start, cargo_input) = blocks
+            .next()
+            .ok_or_else(|| Error::missing_token("", "a cargo drawing"))?;
+        let (instructions_start, instructions) = blocks
+            .next()
+            .ok_or_else(|| Error::missing_token("", "a blank line before the instructions"))?;
+
+        let cargo = Cargo::try_from(cargo_input).map_err(|err| err.offset_lines(cargo_start))?;
+        // Both cranes move the same number of crates, so heights do not
+        // depend on which one runs the procedure.
+        let mut heights: Vec<usize> = cargo.stacks.iter().map(Vec::len).collect();
+        let instructions = parse_lines(instructions, |line| {
+            let instruction = Instruction::try_from(line)?;
+            for stack in [instruction.source, instruction.destination] {
+                if stack == 0 || stack > cargo.stacks.len() {
+                    return Err(Error::invalid(line, "unknown stack in"));
+                }
+            }
+
+            if heights[instruction.source - 1] < instruction.count {
+                return Err(Error::invalid(line, "not enough crates for"));
+            }
+
+            heights[instruction.source - 1] -= instruction.count;
+            heights[instruction.destination - 1] += instruction.count;
+            Ok(instruction)
+        })
+        .map_err(|err| err.offset_lines(instructions_start))?;
+
+        Ok(Procedure {
+            cargo,
+            instructions,
+        })
+    }
+
+    fn part1(procedure: &Self::Input) -> String {
+        procedure.run(true).top_crates()
+    }
+
+    fn part2(procedure: &Self::Input) -> String {
+        procedure.run(false).top_crates()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2",
+    );
+
+    #[test]
+    fn example() {
+        let procedure = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&procedure), "CMZ");
+        assert_eq!(Day5::part2(&procedure), "MCD");
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let procedure = Day5::parse(&input).unwrap();
+            assert_eq!(Day5::part1(&procedure), "CMZ");
+            assert_eq!(Day5::part2(&procedure), "MCD");
+        }
+    }
+
+    #[test]
+    fn cargo_without_trailing_spaces() {
+        let cargo = Cargo::try_from("    [D]\n[N] [C]\n 1   2   3").unwrap();
+        assert_eq!(cargo.stacks, vec![vec!['N'], vec!['C', 'D'], vec![]]);
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let err = Instruction::try_from("move 1 form 2 to 3").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (6, "1 form 2 to 3"));
+
+        let err = Instruction::try_from("move 1 from x to 3").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (13, "x"));
+    }
+
+    #[test]
+    fn not_enough_crates() {
+        let err = Day5::parse("[A]\n 1   2\n\nmove 5 from 1 to 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: not enough crates for `move 5 from 1 to 2`"
+        );
+
+        let input = "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 1 from 2 to 1\nmove 2 from 1 to 2";
+        let err = Day5::parse(input).unwrap_err();
+        assert_eq!(err.line, 6);
+
+        let procedure = Day5::parse("[A]\n 1   2\n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(Day5::part1(&procedure), " A");
+    }
+
+    #[test]
+    fn unknown_stack() {
+        let input = EXAMPLE.replace("move 3 from 1 to 3", "move 3 from 1 to 4");
+        let err = Day5::parse(&input).unwrap_err();
+        assert_eq!(err.line, 7);
+    }
+}
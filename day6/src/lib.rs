@@ -0,0 +1,120 @@
+//! Day 6: Tuning Trouble.
+//!
+//! Finds the first start-of-packet and start-of-message markers in a
+//! datastream.
+#![warn(missing_docs)]
+
+use common::{Error, Solution};
+use std::{collections::HashSet, fmt};
+
+/// Solver for day 6, parsing the input into the characters of the stream.
+pub struct Day6;
+
+/// Where the first marker of a stream ends, shown as `no marker` when the
+/// stream holds none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker(Option<usize>);
+
+impl Marker {
+    /// Number of characters read once the marker has been seen.
+    pub fn end(self) -> Option<usize> {
+        self.0
+    }
+}
+
+impl fmt::Display for Marker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(end) => write!(f, "{}", end),
+            None => write!(f, "no marker"),
+        }
+    }
+}
+
+/// Number of characters read once the first `size` distinct characters in a
+/// row have been seen, `None` when the stream holds no such marker.
+pub fn find_marker(chars: &[char], size: usize) -> Option<usize> {
+    chars
+        .windows(size)
+        .position(|window| window.iter().collect::<HashSet<&char>>().len() == size)
+        .map(|start| start + size)
+}
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+    type Answer1 = Marker;
+    type Answer2 = Marker;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part1(chars: &Self::Input) -> Marker {
+        Marker(find_marker(chars, 4))
+    }
+
+    fn part2(chars: &Self::Input) -> Marker {
+        Marker(find_marker(chars, 14))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn examples() {
+        for (input, part_1, part_2) in EXAMPLES {
+            let chars = Day6::parse(input).unwrap();
+            assert_eq!(Day6::part1(&chars).end(), Some(part_1), "{}", input);
+            assert_eq!(Day6::part2(&chars).end(), Some(part_2), "{}", input);
+        }
+    }
+
+    #[test]
+    fn line_endings() {
+        for input in ["abcd\n", "abcd\r\n"] {
+            assert_eq!(Day6::parse(input).unwrap(), vec!['a', 'b', 'c', 'd']);
+        }
+    }
+
+    #[test]
+    fn marker_at_start() {
+        assert_eq!(find_marker(&['a', 'b', 'c', 'd'], 4), Some(4));
+    }
+
+    #[test]
+    fn missing_marker() {
+        let chars = Day6::parse("abcd").unwrap();
+        assert_eq!(Day6::part1(&chars).to_string(), "4");
+        assert_eq!(Day6::part2(&chars).to_string(), "no marker");
+        assert_eq!(find_marker(&['a', 'a', 'a', 'a'], 2), None);
+    }
+
+    fn naive_marker(chars: &[char], size: usize) -> Option<usize> {
+        (size..=chars.len()).find(|&end| {
+            let window = &chars[end - size..end];
+            window
+                .iter()
+                .enumerate()
+                .all(|(i, c)| !window[i + 1..].contains(c))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn marker_matches_naive_scan(stream in "[a-n]{0,60}", size in 1..=14usize) {
+            let chars: Vec<char> = stream.chars().collect();
+            prop_assert_eq!(find_marker(&chars, size), naive_marker(&chars, size));
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! Day 7: No Space Left On Device.
+//!
+//! Rebuilds a file system from a terminal session of `cd` and `ls` commands
+//! and looks for directories worth deleting.
+#![warn(missing_docs)]
+
+use common::{map_lines, parse_in, parse_number, split_pair, Error, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Size of the disk.
+const DISK_SIZE: u64 = 70000000;
+/// Free space the update needs.
+const UPDATE_SIZE: u64 = 30000000;
+
+#[derive(Debug)]
+enum FSEntry {
+    File(File),
+    Directory(Directory),
+}
+
+#[derive(Debug)]
+struct File {
+    size: u32,
+}
+
+#[derive(Debug)]
+struct Directory {
+    children: HashMap<String, usize>,
+    parent: usize,
+}
+
+/// An entry of a directory listing, see [`AppendOnlyFS::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Listing<'a> {
+    /// A directory along with the total size of the files below it.
+    Directory {
+        /// Name of the directory within its parent.
+        name: &'a str,
+        /// Total size of the files below the directory.
+        size: u32,
+    },
+    /// A file along with its size.
+    File {
+        /// Name of the file within its parent.
+        name: &'a str,
+        /// Size of the file.
+        size: u32,
+    },
+}
+
+/// A file system that only ever grows, along with the current directory of
+/// the terminal session that built it.
+#[derive(Debug)]
+pub struct AppendOnlyFS {
+    current: usize,
+    entries: Vec<FSEntry>,
+    used: u64,
+}
+
+impl Default for AppendOnlyFS {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            entries: vec![FSEntry::Directory(Directory {
+                children: HashMap::new(),
+                parent: 0,
+            })],
+            used: 0,
+        }
+    }
+}
+
+impl AppendOnlyFS {
+    /// A file system holding only the root directory, which is also the
+    /// current directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn current_dir(&self, name: &str) -> Result<&Directory, Error> {
+        match self.entries.get(self.current) {
+            Some(FSEntry::Directory(dir)) => Ok(dir),
+            _ => Err(Error::invalid(name, "no current directory for")),
+        }
+    }
+
+    fn current_dir_mut(&mut self, name: &str) -> Result<&mut Directory, Error> {
+        match self.entries.get_mut(self.current) {
+            Some(FSEntry::Directory(dir)) => Ok(dir),
+            _ => Err(Error::invalid(name, "no current directory for")),
+        }
+    }
+
+    fn move_to_parent(&mut self) -> Result<(), Error> {
+        let current_dir = self.current_dir("..")?;
+
+        self.current = current_dir.parent;
+        Ok(())
+    }
+
+    fn insert_file(&mut self, name: &str, size: u32) -> Result<(), Error> {
+        let current_dir = self.current_dir(name)?;
+
+        if current_dir.children.contains_key(name) {
+            return Err(Error::invalid(name, "duplicate file"));
+        }
+
+        let new_file = File { size };
+        let new_file_index = self.entries.len();
+        let current_dir = self.current_dir_mut(name)?;
+
+        current_dir.children.insert(name.into(), new_file_index);
+        self.entries.push(FSEntry::File(new_file));
+        self.used += u64::from(size);
+        Ok(())
+    }
+
+    fn move_into_or_insert(&mut self, name: &str) -> Result<(), Error> {
+        let current_dir = self.current_dir(name)?;
+
+        if let Some(child_idx) = current_dir.children.get(name) {
+            return match self.entries.get(*child_idx) {
+                Some(FSEntry::Directory(_)) => {
+                    self.current = *child_idx;
+                    Ok(())
+                }
+                _ => Err(Error::invalid(name, "cannot cd into file")),
+            };
+        }
+
+        let new_dir = Directory {
+            children: HashMap::new(),
+            parent: self.current,
+        };
+
+        let new_dir_index = self.entries.len();
+        let current_dir = self.current_dir_mut(name)?;
+
+        current_dir.children.insert(name.into(), new_dir_index);
+        self.entries.push(FSEntry::Directory(new_dir));
+        self.current = new_dir_index;
+        Ok(())
+    }
+
+    fn size(&self, pos: &usize) -> Option<u32> {
+        let mut size = 0;
+        let mut to_visit: Vec<&usize> = vec![pos];
+        while let Some(current) = to_visit.pop() {
+            let current = self.entries.get(*current)?;
+            match current {
+                FSEntry::Directory(dir) => {
+                    dir.children.iter().for_each(|(_, value)| {
+                        to_visit.push(value);
+                    });
+                    continue;
+                }
+                FSEntry::File(file) => {
+                    size += file.size;
+                }
+            };
+        }
+
+        Some(size)
+    }
+
+    fn dir_size(&self, index: usize) -> Option<u32> {
+        match self.entries.get(index)? {
+            FSEntry::Directory(_) => self.size(&index),
+            _ => None,
+        }
+    }
+
+    /// Index of the entry at `path`, where `path` is made of `/` separated
+    /// names and `..` starting from the root.
+    fn resolve(&self, path: &str) -> Option<usize> {
+        let mut index = 0;
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            let FSEntry::Directory(dir) = &self.entries[index] else {
+                return None;
+            };
+
+            index = match name {
+                ".." => dir.parent,
+                _ => *dir.children.get(name)?,
+            };
+        }
+
+        Some(index)
+    }
+
+    /// Size of the file at `path`, or total size of the files below the
+    /// directory at `path`. `None` when nothing was seen at `path`.
+    pub fn size_of(&self, path: &str) -> Option<u32> {
+        self.size(&self.resolve(path)?)
+    }
+
+    /// Total size of every file.
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Entries of the directory at `path`, sorted by name. `None` when `path`
+    /// is not a known directory.
+    pub fn list(&self, path: &str) -> Option<Vec<Listing<'_>>> {
+        let FSEntry::Directory(dir) = self.entries.get(self.resolve(path)?)? else {
+            return None;
+        };
+
+        let mut listing: Vec<Listing> = dir
+            .children
+            .iter()
+            .map(|(name, index)| match &self.entries[*index] {
+                FSEntry::Directory(_) => Listing::Directory {
+                    name,
+                    size: self.size(index).unwrap_or(0),
+                },
+                FSEntry::File(file) => Listing::File {
+                    name,
+                    size: file.size,
+                },
+            })
+            .collect();
+        listing.sort_by_key(|entry| match entry {
+            Listing::Directory { name, .. } | Listing::File { name, .. } => *name,
+        });
+
+        Some(listing)
+    }
+
+    /// Total size of the files below every directory, starting with the root
+    /// and then in the order the directories were first entered.
+    #[cfg(not(feature = "parallel"))]
+    pub fn dir_sizes(&self) -> Vec<u32> {
+        self.dir_sizes_sequential()
+    }
+
+    /// [`AppendOnlyFS::dir_sizes`] computed on the current thread, whichever
+    /// features are enabled.
+    pub fn dir_sizes_sequential(&self) -> Vec<u32> {
+        (0..self.entries.len())
+            .filter_map(|i| self.dir_size(i))
+            .collect()
+    }
+
+    /// Total size of the files below every directory, starting with the root
+    /// and then in the order the directories were first entered.
+    #[cfg(feature = "parallel")]
+    pub fn dir_sizes(&self) -> Vec<u32> {
+        (0..self.entries.len())
+            .into_par_iter()
+            .filter_map(|i| self.dir_size(i))
+            .collect()
+    }
+}
+
+/// Builds the file system seen by a whole terminal session.
+pub fn replay(input: &str) -> Result<AppendOnlyFS, Error> {
+    let mut fs = AppendOnlyFS::new();
+
+    map_lines(input, |line| replay_line(&mut fs, line)).collect::<Result<(), Error>>()?;
+
+    Ok(fs)
+}
+
+/// Applies one line of a terminal session, either a command or a line of
+/// `ls` output, to `fs`.
+pub fn replay_line(fs: &mut AppendOnlyFS, line: &str) -> Result<(), Error> {
+    if line == "$ cd /" {
+        fs.current = 0;
+        return Ok(());
+    }
+
+    if line == "$ cd .." {
+        return fs.move_to_parent();
+    }
+
+    if line == "$ ls" {
+        return Ok(());
+    }
+
+    if let Some(name) = line.strip_prefix("$ cd ") {
+        return parse_in(line, name, |name| fs.move_into_or_insert(name));
+    }
+
+    if line.starts_with("dir") {
+        return Ok(());
+    }
+
+    let (size, name) = split_pair(line, " ", "a file name")?;
+    let size = parse_number(size)?;
+    parse_in(line, name, |name| fs.insert_file(name, size))
+}
+
+/// Solver for day 7, parsing the input into an [`AppendOnlyFS`]. Parsing
+/// fails when the files do not fit on the disk, or when there is already
+/// enough free space for the update.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = AppendOnlyFS;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut fs = AppendOnlyFS::new();
+        map_lines(input, |line| {
+            replay_line(&mut fs, line)?;
+            match fs.used > DISK_SIZE {
+                true => Err(Error::invalid(line, "disk full with")),
+                false => Ok(()),
+            }
+        })
+        .collect::<Result<(), Error>>()?;
+
+        match fs.used > DISK_SIZE - UPDATE_SIZE {
+            true => Ok(fs),
+            false => {
+                let last = input.lines().count().saturating_sub(1);
+                let line = input.lines().last().unwrap_or(input);
+                Err(
+                    Error::invalid(line, "enough free space for the update after")
+                        .offset_lines(last),
+                )
+            }
+        }
+    }
+
+    fn part1(fs: &Self::Input) -> u32 {
+        fs.dir_sizes().iter().filter(|size| **size <= 100000).sum()
+    }
+
+    fn part2(fs: &Self::Input) -> u32 {
+        let to_free = fs.used.saturating_sub(DISK_SIZE - UPDATE_SIZE);
+        fs.dir_sizes()
+            .into_iter()
+            .filter(|size| u64::from(*size) >= to_free)
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn example() {
+        let fs = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&fs), 95437);
+        assert_eq!(Day7::part2(&fs), 24933642);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let fs = Day7::parse(&input).unwrap();
+            assert_eq!(Day7::part1(&fs), 95437);
+            assert_eq!(Day7::part2(&fs), 24933642);
+        }
+    }
+
+    #[test]
+    fn revisited_directory_is_not_duplicated() {
+        let fs = replay("$ cd /\n$ cd a\n10 f\n$ cd ..\n$ cd a\n20 g").unwrap();
+        assert_eq!(fs.dir_sizes(), vec![30, 30]);
+    }
+
+    #[test]
+    fn paths() {
+        let fs = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(fs.size_of("/"), Some(48381165));
+        assert_eq!(fs.size_of("/a/e"), Some(584));
+        assert_eq!(fs.size_of("a/e/../h.lst"), Some(62596));
+        assert_eq!(fs.size_of("/x"), None);
+        assert_eq!(fs.size_of("/b.txt/c"), None);
+
+        assert_eq!(
+            fs.list("/a"),
+            Some(vec![
+                Listing::Directory {
+                    name: "e",
+                    size: 584
+                },
+                Listing::File {
+                    name: "f",
+                    size: 29116
+                },
+                Listing::File {
+                    name: "g",
+                    size: 2557
+                },
+                Listing::File {
+                    name: "h.lst",
+                    size: 62596
+                },
+            ])
+        );
+        assert_eq!(fs.list("/b.txt"), None);
+    }
+
+    #[test]
+    fn duplicate_file() {
+        let err = Day7::parse("$ cd /\n$ ls\n10 f\n20 f").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 4));
+        assert_eq!(err.token, "f");
+    }
+
+    #[test]
+    fn disk_size() {
+        let err = Day7::parse("$ cd /\n100 a").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: enough free space for the update after `100 a`"
+        );
+
+        let err = Day7::parse("$ cd /\n40000000 a\n30000001 b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: disk full with `30000001 b`"
+        );
+
+        let fs = Day7::parse("$ cd /\n$ cd a\n5 b\n$ cd ..\n40000000 c").unwrap();
+        assert_eq!(fs.used(), 40000005);
+        assert_eq!(Day7::part2(&fs), 5);
+    }
+
+    /// A session walking down a chain of directories, stepping back up every
+    /// few of them, with a couple of files in each.
+    fn generated(directories: usize) -> String {
+        let mut lines = vec!["$ cd /".to_owned()];
+        for directory in 0..directories {
+            match directory % 4 {
+                3 => lines.push("$ cd ..".to_owned()),
+                _ => lines.push(format!("$ cd d{}", directory)),
+            }
+            lines.push("$ ls".to_owned());
+            lines.push(format!("{} a{}", directory * 37 % 1000 + 1, directory));
+            lines.push(format!("{} b{}", directory * 91 % 5000 + 1, directory));
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let fs = replay(&generated(1000)).unwrap();
+        assert_eq!(fs.dir_sizes(), fs.dir_sizes_sequential());
+    }
+}
@@ -0,0 +1,357 @@
+//! Day 8: Treetop Tree House.
+//!
+//! Counts the trees visible from outside a grid of tree heights and finds the
+//! tree with the best view.
+#![warn(missing_docs)]
+
+use common::{Error, Grid, Point, Ray, Solution, NEIGHBORS_4};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// A grid of tree heights from 0 to 9.
+#[derive(Debug)]
+pub struct Forest {
+    trees: Grid<u32>,
+}
+
+impl Forest {
+    /// The tree heights.
+    pub fn trees(&self) -> &Grid<u32> {
+        &self.trees
+    }
+
+    /// Width of the forest.
+    pub fn columns(&self) -> usize {
+        self.trees.columns()
+    }
+
+    /// Height of the forest.
+    pub fn rows(&self) -> usize {
+        self.trees.rows()
+    }
+
+    /// Walks from `position` (inclusive) in `step` increments to the edge.
+    ///
+    /// # Panics
+    ///
+    /// When `step` is [`Point::ZERO`], see [`Grid::ray`].
+    pub fn iter(&self, position: Point, step: Point) -> ForestIterator<'_> {
+        ForestIterator {
+            ray: self.trees.ray(position, step),
+        }
+    }
+
+    /// Walks down a column, or up with `reverse`.
+    pub fn iter_col(&self, col: usize, reverse: bool) -> ForestIterator<'_> {
+        let col = col as i32;
+        match reverse {
+            true => self.iter(Point::new(col, self.rows() as i32 - 1), Point::UP),
+            false => self.iter(Point::new(col, 0), Point::DOWN),
+        }
+    }
+
+    /// Walks right along a row, or left with `reverse`.
+    pub fn iter_row(&self, row: usize, reverse: bool) -> ForestIterator<'_> {
+        let row = row as i32;
+        match reverse {
+            true => self.iter(Point::new(self.columns() as i32 - 1, row), Point::LEFT),
+            false => self.iter(Point::new(0, row), Point::RIGHT),
+        }
+    }
+
+    /// Every tree visible from outside the forest, looking along a row or a
+    /// column.
+    pub fn visible_trees(&self) -> HashSet<Point> {
+        let directions = [true, false];
+        let mut visible: HashSet<Point> = HashSet::new();
+        for row in 0..self.rows() {
+            for &reverse in directions.iter() {
+                visible.extend(
+                    self.iter_row(row, reverse)
+                        .visible(None)
+                        .iter()
+                        .map(|(_, pos)| *pos),
+                );
+            }
+        }
+        for col in 0..self.columns() {
+            for &reverse in directions.iter() {
+                visible.extend(
+                    self.iter_col(col, reverse)
+                        .visible(None)
+                        .iter()
+                        .map(|(_, pos)| *pos),
+                );
+            }
+        }
+
+        visible
+    }
+
+    /// Product of the viewing distances in all four directions, `None`
+    /// outside the forest.
+    pub fn scenic_score(&self, position: Point) -> Option<usize> {
+        self.trees.get(position)?;
+
+        Some(
+            NEIGHBORS_4
+                .iter()
+                .map(|direction| {
+                    self.iter(position, *direction)
+                        .viewing_distance()
+                        .unwrap_or(0)
+                })
+                .product(),
+        )
+    }
+}
+
+impl TryFrom<&str> for Forest {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let trees = Grid::parse(value, |char| {
+            char.to_digit(10)
+                .ok_or_else(|| Error::invalid_token(&char.to_string(), "a tree height digit"))
+        })?;
+
+        Ok(Self { trees })
+    }
+}
+
+/// Iterator over `(height, position)` of the trees along a straight line,
+/// see [`Forest::iter`].
+pub struct ForestIterator<'a> {
+    ray: Ray<'a, u32>,
+}
+
+impl ForestIterator<'_> {
+    /// The trees visible when looking from before the start of the line,
+    /// over a tree of height `current` if given.
+    pub fn visible(&mut self, current: Option<u32>) -> Vec<(u32, Point)> {
+        let mut max: Option<u32> = current;
+        self.filter(|(value, _)| {
+                let is_visible = match max {
+                    Some(max) => *value > max,
+                    _ => true,
+                };
+
+                if is_visible {
+                    max = Some(*value);
+                    return true;
+                }
+
+                false
+            })
+            .collect()
+    }
+
+    /// Number of trees seen from the first tree until one at least as tall
+    /// blocks the view, `None` when the line is empty.
+    pub fn viewing_distance(&mut self) -> Option<usize> {
+        let view_line: Vec<_> = self.collect();
+        let current = view_line.first()?.0;
+
+        Some(
+            view_line
+                .iter()
+                .skip(1)
+                .position(|(value, _)| value >= &current)
+                .map(|pos| pos + 1)
+                .unwrap_or(view_line.len() - 1),
+        )
+    }
+}
+
+impl Iterator for ForestIterator<'_> {
+    type Item = (u32, Point);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, value) = self.ray.next()?;
+        Some((*value, position))
+    }
+}
+
+/// Solver for day 8, parsing the input into a [`Forest`].
+pub struct Day8;
+
+impl Day8 {
+    /// Highest scenic score, computed on the current thread as
+    /// [`Solution::part2`] does without the `parallel` feature.
+    pub fn part2_sequential(forest: &Forest) -> usize {
+        forest
+            .trees
+            .positions()
+            .map(|position| forest.scenic_score(position).unwrap())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Solution for Day8 {
+    type Input = Forest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Forest::try_from(input)
+    }
+
+    fn part1(forest: &Self::Input) -> usize {
+        forest.visible_trees().len()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part2(forest: &Self::Input) -> usize {
+        Self::part2_sequential(forest)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn part2(forest: &Self::Input) -> usize {
+        (0..forest.rows())
+            .into_par_iter()
+            .flat_map_iter(|row| forest.trees.row(row).map(|(position, _)| position))
+            .map(|position| forest.scenic_score(position).unwrap())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn example() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&forest), 21);
+        assert_eq!(Day8::part2(&forest), 8);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let forest = Day8::parse(&input).unwrap();
+            assert_eq!(Day8::part1(&forest), 21);
+            assert_eq!(Day8::part2(&forest), 8);
+        }
+    }
+
+    #[test]
+    fn viewing_distance() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+
+        // Up, left, down and right from the middle 5 in the second row.
+        let position = Point::new(2, 1);
+        assert_eq!(forest.iter(position, Point::UP).viewing_distance(), Some(1));
+        assert_eq!(
+            forest.iter(position, Point::LEFT).viewing_distance(),
+            Some(1)
+        );
+        assert_eq!(
+            forest.iter(position, Point::DOWN).viewing_distance(),
+            Some(2)
+        );
+        assert_eq!(
+            forest.iter(position, Point::RIGHT).viewing_distance(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn viewing_distance_at_edge() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            forest.iter(Point::new(0, 0), Point::UP).viewing_distance(),
+            Some(0)
+        );
+        assert_eq!(
+            forest
+                .iter(Point::new(4, 4), Point::RIGHT)
+                .viewing_distance(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn viewing_distance_outside_forest() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            forest
+                .iter(Point::new(0, 5), Point::DOWN)
+                .viewing_distance(),
+            None
+        );
+    }
+
+    #[test]
+    fn scenic_score() {
+        let forest = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(forest.scenic_score(Point::new(2, 1)), Some(4));
+        assert_eq!(forest.scenic_score(Point::new(2, 3)), Some(8));
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let rows: Vec<String> = (0..80usize)
+            .map(|row| {
+                (0..80usize)
+                    .map(|col| {
+                        char::from(b'0' + ((row * row * 7 + col * 13 + row * col) % 10) as u8)
+                    })
+                    .collect()
+            })
+            .collect();
+        let forest = Day8::parse(&rows.join("\n")).unwrap();
+        assert_eq!(Day8::part2(&forest), Day8::part2_sequential(&forest));
+    }
+
+    fn naive_visible(heights: &[Vec<u32>]) -> usize {
+        let (rows, columns) = (heights.len(), heights[0].len());
+        let mut visible = 0;
+        for row in 0..rows {
+            for col in 0..columns {
+                let height = heights[row][col];
+                let lines_of_sight = [
+                    (0..row).all(|r| heights[r][col] < height),
+                    (row + 1..rows).all(|r| heights[r][col] < height),
+                    (0..col).all(|c| heights[row][c] < height),
+                    (col + 1..columns).all(|c| heights[row][c] < height),
+                ];
+                if lines_of_sight.contains(&true) {
+                    visible += 1;
+                }
+            }
+        }
+
+        visible
+    }
+
+    fn any_forest() -> impl Strategy<Value = Vec<Vec<u32>>> {
+        (1..10usize, 1..10usize).prop_flat_map(|(rows, columns)| {
+            prop::collection::vec(prop::collection::vec(0..10u32, columns), rows)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn visibility_matches_naive_check(heights in any_forest()) {
+            let input: Vec<String> = heights
+                .iter()
+                .map(|row| row.iter().map(|height| height.to_string()).collect())
+                .collect();
+            let forest = Day8::parse(&input.join("\n")).unwrap();
+            prop_assert_eq!(Day8::part1(&forest), naive_visible(&heights));
+        }
+    }
+}
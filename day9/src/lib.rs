@@ -0,0 +1,284 @@
+//! Day 9: Rope Bridge.
+//!
+//! Simulates a rope of knots following its head and counts the positions the
+//! tail visits.
+#![warn(missing_docs)]
+
+use common::{offset_in, parse_in, parse_lines, parse_number, split_pair, Error, Point, Solution};
+use std::collections::HashSet;
+
+/// A single step of the head, parsed from `U`, `D`, `L` or `R`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Towards negative `y`, [`Point::UP`].
+    Up,
+    /// Towards positive `y`, [`Point::DOWN`].
+    Down,
+    /// Towards negative `x`, [`Point::LEFT`].
+    Left,
+    /// Towards positive `x`, [`Point::RIGHT`].
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' => Ok(Direction::Up),
+            'D' => Ok(Direction::Down),
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(Error::invalid_token(&c.to_string(), "one of U, D, L, R")),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Point::UP,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+            Direction::Right => Point::RIGHT,
+        }
+    }
+}
+
+/// A rope of knots, the head first, where every knot stays touching the one
+/// before it.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    joints: Vec<Point>,
+}
+
+impl Rope {
+    /// A rope of `size` knots, all stacked at the origin.
+    ///
+    /// # Panics
+    ///
+    /// When `size` is 0.
+    pub fn with_size(size: usize) -> Self {
+        assert!(size > 0, "a rope needs at least one knot");
+        Self {
+            joints: vec![Point::ZERO; size],
+        }
+    }
+
+    /// Positions of the knots, from the head to the tail.
+    pub fn joints(&self) -> &[Point] {
+        &self.joints
+    }
+
+    /// Position of the last knot.
+    pub fn tail(&self) -> Point {
+        *self.joints.last().unwrap()
+    }
+}
+
+impl Rope {
+    /// Moves the head one step and lets every following knot catch up.
+    pub fn move_head(&mut self, direction: &Direction) {
+        let head = self.joints.first_mut().unwrap();
+        *head += Point::from(*direction);
+
+        let mut previous = *head;
+        self.joints.iter_mut().skip(1).for_each(|joint| {
+            if previous.chebyshev(*joint) > 1 {
+                *joint += (previous - *joint).signum();
+            }
+            previous = *joint;
+        });
+    }
+}
+
+/// Number of distinct positions the tail of a rope of `size` knots visits
+/// after each step of the `motions`, given as a direction and a number of
+/// steps.
+pub fn count_tail_positions(motions: &[(Direction, usize)], size: usize) -> usize {
+    let mut rope = Rope::with_size(size);
+    let mut visited: HashSet<Point> = HashSet::new();
+    for (direction, distance) in motions {
+        for _ in 0..*distance {
+            rope.move_head(direction);
+            visited.insert(rope.tail());
+        }
+    }
+
+    visited.len()
+}
+
+/// Solver for day 9, parsing the input into the motions of the head, each a
+/// direction and a number of steps. Parsing fails when the head would leave
+/// the range of a [`Point`].
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(Direction, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut head = (0i64, 0i64);
+        parse_lines(input, |s| {
+            let (direction, distance) = split_pair(s, " ", "a distance")?;
+            let direction = match direction.chars().collect::<Vec<char>>()[..] {
+                [direction] => Direction::try_from(direction)?,
+                _ => return Err(Error::invalid_token(direction, "one of U, D, L, R")),
+            };
+            let steps = parse_in(s, distance, parse_number::<usize>)?;
+
+            let step = Point::from(direction);
+            let reach = i64::try_from(steps).ok().and_then(|steps| {
+                let x = head.0.checked_add(i64::from(step.x) * steps)?;
+                let y = head.1.checked_add(i64::from(step.y) * steps)?;
+                (i32::try_from(x).is_ok() && i32::try_from(y).is_ok()).then_some((x, y))
+            });
+            head = reach.ok_or_else(|| {
+                Error::invalid(distance, "head out of range after")
+                    .offset_columns(offset_in(s, distance))
+            })?;
+
+            Ok((direction, steps))
+        })
+    }
+
+    fn part1(moves: &Self::Input) -> usize {
+        count_tail_positions(moves, 2)
+    }
+
+    fn part2(moves: &Self::Input) -> usize {
+        count_tail_positions(moves, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn example() {
+        let moves = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&moves), 13);
+        assert_eq!(Day9::part2(&moves), 1);
+    }
+
+    #[test]
+    fn line_endings() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+        for input in [format!("{}\n", EXAMPLE), format!("{}\r\n", crlf)] {
+            let moves = Day9::parse(&input).unwrap();
+            assert_eq!(Day9::part1(&moves), 13);
+            assert_eq!(Day9::part2(&moves), 1);
+        }
+    }
+
+    #[test]
+    fn larger_example() {
+        let moves = Day9::parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&moves), 36);
+    }
+
+    #[test]
+    fn distance_out_of_range() {
+        let err = Day9::parse("R 18446744073709551615").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: head out of range after `18446744073709551615`"
+        );
+
+        let err = Day9::parse("U 1\nR 2147483647\nR 1").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert!(Day9::parse("L 2147483649").is_err());
+        assert!(Day9::parse("L 2147483648\nR 2147483648\nR 2147483647").is_ok());
+    }
+
+    #[test]
+    fn tail_stays_when_touching() {
+        let mut rope = Rope::with_size(2);
+        rope.move_head(&Direction::Right);
+        assert_eq!(rope.joints, vec![Point::new(1, 0), Point::ZERO]);
+
+        rope.move_head(&Direction::Up);
+        assert_eq!(rope.joints, vec![Point::new(1, -1), Point::ZERO]);
+    }
+
+    #[test]
+    fn tail_follows_straight() {
+        let mut rope = Rope::with_size(2);
+        rope.move_head(&Direction::Left);
+        rope.move_head(&Direction::Left);
+        assert_eq!(rope.joints, vec![Point::new(-2, 0), Point::new(-1, 0)]);
+    }
+
+    #[test]
+    fn tail_follows_diagonally() {
+        let mut rope = Rope::with_size(2);
+        rope.move_head(&Direction::Right);
+        rope.move_head(&Direction::Up);
+        rope.move_head(&Direction::Up);
+        assert_eq!(rope.joints, vec![Point::new(1, -2), Point::new(1, -1)]);
+    }
+
+    #[test]
+    fn knots_follow_each_other() {
+        let mut rope = Rope::with_size(3);
+        for _ in 0..3 {
+            rope.move_head(&Direction::Down);
+        }
+        assert_eq!(
+            rope.joints,
+            vec![Point::new(0, 3), Point::new(0, 2), Point::new(0, 1)]
+        );
+    }
+
+    fn any_direction() -> impl Strategy<Value = Direction> {
+        prop_oneof![
+            Just(Direction::Up),
+            Just(Direction::Down),
+            Just(Direction::Left),
+            Just(Direction::Right),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn knots_stay_touching(
+            size in 2..12usize,
+            moves in prop::collection::vec(any_direction(), 0..200),
+        ) {
+            let mut rope = Rope::with_size(size);
+            for direction in &moves {
+                let before = rope.joints.clone();
+                rope.move_head(direction);
+
+                for (previous, joint) in rope.joints.iter().zip(&rope.joints[1..]) {
+                    prop_assert!(previous.chebyshev(*joint) <= 1);
+                }
+                for (before, after) in before.iter().zip(&rope.joints) {
+                    prop_assert!(before.chebyshev(*after) <= 1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! WebAssembly bindings for the solvers, built for `wasm32-unknown-unknown`.
+//!
+//! ```js
+//! import init, { days, solve } from "./pkg/aoc_wasm.js";
+//!
+//! await init();
+//! const answers = solve(1, input);
+//! console.log(answers.part1, answers.part2);
+//! ```
+#![warn(missing_docs)]
+
+use common::{normalize_input, Solution};
+use wasm_bindgen::prelude::*;
+
+/// The answers to both parts of a day, as displayed by the runner.
+#[wasm_bindgen]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    part1: String,
+    part2: String,
+}
+
+#[wasm_bindgen]
+impl Answers {
+    /// The answer to the first part.
+    #[wasm_bindgen(getter)]
+    pub fn part1(&self) -> String {
+        self.part1.clone()
+    }
+
+    /// The answer to the second part.
+    #[wasm_bindgen(getter)]
+    pub fn part2(&self) -> String {
+        self.part2.clone()
+    }
+}
+
+fn run<S: Solution>(input: &str) -> Result<Answers, String> {
+    let parsed = S::parse(&normalize_input(input)).map_err(|err| err.to_string())?;
+
+    Ok(Answers {
+        part1: S::part1(&parsed).to_string(),
+        part2: S::part2(&parsed).to_string(),
+    })
+}
+
+macro_rules! days {
+    ($($day:literal => $solution:ty,)*) => {
+        /// Every day with a registered solution.
+        const DAYS: &[u8] = &[$($day),*];
+
+        /// Solves both parts of `day`, describing invalid input or an unknown
+        /// day in the error.
+        pub fn solve_day(day: u8, input: &str) -> Result<Answers, String> {
+            match day {
+                $($day => run::<$solution>(input),)*
+                _ => Err(format!("day {} is not solved yet", day)),
+            }
+        }
+    };
+}
+
+days! {
+    1 => day1::Day1,
+    2 => day2::Day2,
+    3 => day3::Day3,
+    4 => day4::Day4,
+    5 => day5::Day5,
+    6 => day6::Day6,
+    7 => day7::Day7,
+    8 => day8::Day8,
+    9 => day9::Day9,
+    10 => day10::Day10,
+}
+
+/// Every day that can be solved.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    DAYS.to_vec()
+}
+
+/// Solves both parts of `day` for the puzzle `input`, throwing an `Error`
+/// for invalid input or an unknown day.
+#[wasm_bindgen]
+pub fn solve(day: u8, input: &str) -> Result<Answers, JsError> {
+    solve_day(day, input).map_err(|err| JsError::new(&err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_with_any_line_endings() {
+        let answers = solve_day(1, "1000\r\n2000\r\n\r\n4000\r\n").unwrap();
+        assert_eq!(answers.part1(), "4000");
+        assert_eq!(answers.part2(), "7000");
+    }
+
+    #[test]
+    fn reports_errors() {
+        let err = solve_day(1, "100\n\n2oo").unwrap_err();
+        assert_eq!(err, "line 3, column 1: invalid number `2oo`");
+        assert_eq!(solve_day(42, "").unwrap_err(), "day 42 is not solved yet");
+    }
+
+    #[test]
+    fn rejects_unsolvable_input() {
+        let err = solve_day(3, "abcdef\nghijkl\nmnopqr").unwrap_err();
+        assert_eq!(
+            err,
+            "line 1, column 1: not exactly one item type in both compartments of `abcdef`"
+        );
+        assert!(solve_day(7, "$ cd /\n100 a").is_err());
+        assert!(solve_day(9, "R 18446744073709551615").is_err());
+        assert!(solve_day(10, "addx 2147483647").is_err());
+    }
+}